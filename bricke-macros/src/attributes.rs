@@ -0,0 +1,1235 @@
+use crate::case::{self, RenameRule};
+use crate::fields;
+use crate::item::SupportedType;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    Arm, Expr, ExprArray, ExprLit, Ident, Lit, LitBool, LitStr, Meta, Path, Result, Type,
+    Visibility, meta::ParseNestedMeta, spanned::Spanned,
+};
+
+/// INTEGER_TYPES are the primitive types that can be used as a source of a fieldless enum
+const INTEGER_TYPES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// BUILTIN_CONVERTERS are the converters generated by bricke, any other converter is the path of a custom trait
+const BUILTIN_CONVERTERS: [&str; 7] = [
+    "From", "TryFrom", "FromStr", "Patch", "Update", "FromWith", "Async",
+];
+
+#[derive(Default)]
+pub enum ConverterType {
+    #[default]
+    From,
+    TryFrom,
+    FromStr,
+    Patch,
+    Update,
+    FromWith,
+    Async,
+    Custom(Path),
+}
+
+/// brickeAttributes is a struct that holds the attributes for the bricke proc macro.
+///
+/// - Converter refers to the type of conversion to be performed (From, TryFrom, FromStr, Patch, Update, FromWith, Async or the path of a custom trait) default = From
+/// - source refers to the struct or enum that the bricke will be converted from
+/// - error_kind refers to the error kind that will be returned if the conversion fails (use in conjunction with TryFrom)
+/// - rename_all refers to the case convention of the source fields or variants
+/// - strip_prefix / strip_suffix refer to the prefix / suffix carried by every source field or variant name
+/// - unknown_fn refers to the function creating the error_kind when the source value doesn't match any variant
+/// - variant refers to the target variant built from a struct source (use on an enum)
+/// - arms refers to the match arms building the target struct from each variant of an enum source (use on a struct)
+/// - case_insensitive refers to whether the string representation of a variant is matched regardless of the case (use in conjunction with FromStr)
+/// - generate refers to the target struct generated from the annotated struct along with omit, rename and derive
+/// - context refers to the type of the context passed to the transform functions (use in conjunction with FromWith)
+/// - concurrent refers to whether the async transform functions run concurrently (use in conjunction with Async)
+/// - method refers to the name of the generated method. It's the method of the custom trait, an inherent constructor
+///   along with From / TryFrom or overrides the default name of the other methods
+/// - into_method refers to the name of the inherent method generated on the source e.g: `fn into_dto(self) -> Target`
+/// - cfg refers to the configuration predicate gating every generated item e.g: `cfg = "feature = \"proto\""`
+/// - vis refers to the visibility of the generated inherent methods, default = pub
+/// - strict refers to whether every field of the source must be consumed by the target or listed in ignore_source
+/// - ignore_source refers to the fields of the source which are deliberately not consumed (use in conjunction with strict)
+/// - krate refers to the path of the runtime crate used by the generated code e.g: `crate = "my_crate::bricke"`,
+///   default = ::bricke
+/// - describe refers to whether the mapping of the fields is exposed at runtime through `bricke::BrickeMapping`
+/// - before refers to the function receiving the source before the conversion e.g: `fn(Source) -> Source`, the
+///   function returns a Result along with a fallible conversion
+/// - after refers to the function receiving the built target e.g: `fn(&mut Target)`
+/// - validate refers to the function checking the invariants of the built target before returning it (use in
+///   conjunction with a fallible conversion)
+#[derive(Default)]
+pub struct BrickeAttributes {
+    pub converter: ConverterType,
+    pub converter_span: Option<Span>,
+    pub source: Option<Path>,
+    pub error_kind: Option<LitStr>,
+    pub rename_all: Option<RenameRule>,
+    pub strip_prefix: Option<LitStr>,
+    pub strip_suffix: Option<LitStr>,
+    pub unknown_fn: Option<Path>,
+    pub case_insensitive: bool,
+    pub variant: Option<Ident>,
+    pub arms: Vec<Arm>,
+    pub generate: Option<GenerateAttributes>,
+    pub context: Option<Type>,
+    pub concurrent: bool,
+    pub method: Option<Ident>,
+    pub into_method: Option<Ident>,
+    pub cfg: Option<Meta>,
+    pub vis: Option<Visibility>,
+    pub validate: Option<Path>,
+    pub strict: bool,
+    pub describe: Option<LitBool>,
+    pub krate: Option<Path>,
+    pub ignore_source: Vec<LitStr>,
+    pub before: Option<Path>,
+    pub after: Option<Path>,
+}
+
+/// GenerateAttributes holds the attributes used to generate a target struct from the annotated struct
+///
+/// - name refers to the name of the generated struct
+/// - omit refers to the fields of the source which are not copied into the generated struct
+/// - rename refers to the fields renamed in the generated struct e.g: `rename(email = "mail")`
+/// - derive refers to the derive macros applied on the generated struct
+#[derive(Default)]
+pub struct GenerateAttributes {
+    pub name: Option<Ident>,
+    pub omit: Vec<LitStr>,
+    pub rename: Vec<(Ident, Ident)>,
+    pub derive: Vec<Path>,
+}
+
+impl BrickeAttributes {
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> Result<()> {
+        if meta.path.get_ident().is_none() {
+            return Err(syn::Error::new(meta.path.span(), "Unknown attribute"));
+        }
+
+        let ident = meta.path.get_ident().unwrap();
+        match ident.to_string().as_str() {
+            "converter" => {
+                let converter: LitStr = meta.value()?.parse()?;
+                self.converter_span = Some(converter.span());
+                self.converter = match converter.value().as_str() {
+                    "From" => ConverterType::From,
+                    "TryFrom" => ConverterType::TryFrom,
+                    "FromStr" => ConverterType::FromStr,
+                    "Patch" => ConverterType::Patch,
+                    "Update" => ConverterType::Update,
+                    "FromWith" => ConverterType::FromWith,
+                    "Async" => ConverterType::Async,
+                    _ => ConverterType::Custom(converter.parse()?),
+                };
+
+                Ok(())
+            }
+            "source" => {
+                let source: Option<LitStr> = meta.value()?.parse()?;
+                if let Some(src) = source {
+                    self.source = Some(src.parse()?)
+                }
+
+                Ok(())
+            }
+            "try_error_kind" => {
+                self.error_kind = Some(meta.value()?.parse()?);
+
+                Ok(())
+            }
+            "rename_all" => {
+                let rule: LitStr = meta.value()?.parse()?;
+                self.rename_all = Some(RenameRule::parse(&rule)?);
+
+                Ok(())
+            }
+            "strip_prefix" => {
+                self.strip_prefix = Some(meta.value()?.parse()?);
+
+                Ok(())
+            }
+            "strip_suffix" => {
+                self.strip_suffix = Some(meta.value()?.parse()?);
+
+                Ok(())
+            }
+            "unknown_fn" => {
+                let unknown_fn: LitStr = meta.value()?.parse()?;
+                self.unknown_fn = Some(unknown_fn.parse_with(Path::parse_mod_style)?);
+
+                Ok(())
+            }
+            "variant" => {
+                let variant: LitStr = meta.value()?.parse()?;
+                self.variant = Some(variant.parse()?);
+
+                Ok(())
+            }
+            "arm" => {
+                let arm: LitStr = meta.value()?.parse()?;
+                self.arms.push(arm.parse()?);
+
+                Ok(())
+            }
+            "case_insensitive" => {
+                let case_insensitive: LitBool = meta.value()?.parse()?;
+                self.case_insensitive = case_insensitive.value();
+
+                Ok(())
+            }
+            "context" => {
+                let context: LitStr = meta.value()?.parse()?;
+                self.context = Some(context.parse()?);
+
+                Ok(())
+            }
+            "concurrent" => {
+                let concurrent: LitBool = meta.value()?.parse()?;
+                self.concurrent = concurrent.value();
+
+                Ok(())
+            }
+            "method" => {
+                let method: LitStr = meta.value()?.parse()?;
+                self.method = Some(method.parse()?);
+
+                Ok(())
+            }
+            "into_method" => {
+                let into_method: LitStr = meta.value()?.parse()?;
+                self.into_method = Some(into_method.parse()?);
+
+                Ok(())
+            }
+            "cfg" => {
+                let cfg: LitStr = meta.value()?.parse()?;
+                self.cfg = Some(cfg.parse()?);
+
+                Ok(())
+            }
+            "vis" => {
+                let vis: LitStr = meta.value()?.parse()?;
+                self.vis = Some(vis.parse()?);
+
+                Ok(())
+            }
+            "validate" => {
+                let validate: LitStr = meta.value()?.parse()?;
+                self.validate = Some(validate.parse_with(Path::parse_mod_style)?);
+
+                Ok(())
+            }
+            "before" => {
+                let before: LitStr = meta.value()?.parse()?;
+                self.before = Some(before.parse_with(Path::parse_mod_style)?);
+
+                Ok(())
+            }
+            "after" => {
+                let after: LitStr = meta.value()?.parse()?;
+                self.after = Some(after.parse_with(Path::parse_mod_style)?);
+
+                Ok(())
+            }
+            "generate" => {
+                let name: LitStr = meta.value()?.parse()?;
+                self.generate.get_or_insert_default().name = Some(name.parse()?);
+
+                Ok(())
+            }
+            "omit" => {
+                let omit = parse_field_names(meta)?;
+                self.generate.get_or_insert_default().omit.extend(omit);
+
+                Ok(())
+            }
+            "strict" => {
+                let strict: LitBool = meta.value()?.parse()?;
+                self.strict = strict.value();
+
+                Ok(())
+            }
+            "crate" => {
+                let krate: LitStr = meta.value()?.parse()?;
+                self.krate = Some(krate.parse_with(Path::parse_mod_style)?);
+
+                Ok(())
+            }
+            "describe" => {
+                self.describe = Some(meta.value()?.parse()?);
+
+                Ok(())
+            }
+            "ignore_source" => {
+                self.ignore_source.extend(parse_field_names(meta)?);
+
+                Ok(())
+            }
+            "rename" => {
+                let generate = self.generate.get_or_insert_default();
+                meta.parse_nested_meta(|field| {
+                    let from = field.path.require_ident()?.clone();
+                    let to: LitStr = field.value()?.parse()?;
+                    generate.rename.push((from, to.parse()?));
+
+                    Ok(())
+                })
+            }
+            "derive" => {
+                let generate = self.generate.get_or_insert_default();
+                meta.parse_nested_meta(|derive| {
+                    generate.derive.push(derive.path);
+
+                    Ok(())
+                })
+            }
+            _ => Err(syn::Error::new(ident.span(), "Unknown attribute")),
+        }
+    }
+
+    /// Get the name of the source field or variant matching the target name. A `rename` set on the field
+    /// takes precedence over the name computed here.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The target field or variant name
+    pub fn source_name(&self, name: &Ident) -> Ident {
+        case::source_name(
+            name,
+            self.rename_all,
+            self.strip_prefix.as_ref(),
+            self.strip_suffix.as_ref(),
+        )
+        .unwrap_or_else(|_| name.clone())
+    }
+
+    /// Create the compile error of a target field or variant whose computed source name isn't a valid identifier
+    /// e.g: `rename_all = "kebab-case"` on fields. The fields providing their source with `rename` or `sources`
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The target names along with their `bricke_field` attributes
+    pub fn source_name_error<'a>(
+        &self,
+        names: impl IntoIterator<Item = (&'a Ident, &'a [fields::BrickeFieldArgs])>,
+    ) -> Option<TokenStream> {
+        names
+            .into_iter()
+            .filter(|(_, field_attrs)| !fields::BrickeFieldArgs::is_renamed(field_attrs))
+            .find_map(|(name, _)| {
+                case::source_name(
+                    name,
+                    self.rename_all,
+                    self.strip_prefix.as_ref(),
+                    self.strip_suffix.as_ref(),
+                )
+                .err()
+            })
+            .map(|err| err.to_compile_error())
+    }
+
+    /// Check whether the source is a primitive integer e.g: `source = "i32"`
+    pub fn has_integer_source(&self) -> bool {
+        self.source
+            .as_ref()
+            .and_then(Path::get_ident)
+            .is_some_and(|source| INTEGER_TYPES.contains(&source.to_string().as_str()))
+    }
+
+    /// Get the name of the string representation of a variant. A `rename` set on the variant takes precedence
+    /// over the name computed here.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The target variant name
+    pub fn source_str(&self, name: &Ident) -> String {
+        case::source_str(
+            name,
+            self.rename_all,
+            self.strip_prefix.as_ref(),
+            self.strip_suffix.as_ref(),
+        )
+    }
+
+    /// Get the error type returned by a TryFrom conversion. When converting from an integer or a string without a
+    /// try_error_kind, the unknown value is returned as the error.
+    fn error_type(&self) -> Type {
+        match (&self.error_kind, &self.source) {
+            (Some(error_kind), _) => {
+                syn::parse_str(&error_kind.value()).expect("Expect to parse error_kind")
+            }
+            (None, _) if matches!(self.converter, ConverterType::FromStr) => {
+                syn::parse_quote!(String)
+            }
+            (None, Some(source)) if self.has_integer_source() => syn::parse_quote!(#source),
+            _ => panic!("Expect try_error_kind to be provided"),
+        }
+    }
+
+    /// Create the expression returning the error of an unknown source value. The value is passed to the
+    /// unknown_fn when a try_error_kind is used, otherwise the value itself is returned as the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The unknown source value
+    pub fn generate_unknown_error(&self, value: TokenStream) -> TokenStream {
+        match (&self.error_kind, &self.unknown_fn) {
+            (None, None) => quote! { Err(::core::convert::Into::into(#value)) },
+            (Some(_), Some(unknown_fn)) => quote! { Err(#unknown_fn(#value)) },
+            (Some(error_kind), None) => {
+                syn::Error::new(error_kind.span(), "Expect unknown_fn to be provided")
+                    .to_compile_error()
+            }
+            (None, Some(unknown_fn)) => syn::Error::new(
+                unknown_fn.span(),
+                "Expect try_error_kind to be provided along with unknown_fn",
+            )
+            .to_compile_error(),
+        }
+    }
+
+    /// Create the reverse conversion of a fieldless enum to its integer source e.g: `impl From<Target> for i32`
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target enum identifier
+    /// * `values` - The variants along with their discriminant
+    pub fn generate_discriminant_template(
+        &self,
+        target_ident: Ident,
+        values: Vec<(Ident, TokenStream)>,
+    ) -> TokenStream {
+        let source = &self.source;
+        let cfg = self.cfg_attr();
+        let (variants, discriminants): (Vec<Ident>, Vec<TokenStream>) = values.into_iter().unzip();
+
+        quote! {
+            #cfg
+            impl From<#target_ident> for #source {
+                fn from(arg: #target_ident) -> Self {
+                    match arg {
+                        #(#target_ident::#variants => #discriminants),*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Create the string conversions of a fieldless enum. This generates `FromStr`, `TryFrom<&str>`, `Display`
+    /// and `From<Target> for &'static str`
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target enum identifier
+    /// * `transform_fields` - The match arms converting a string to a variant
+    /// * `values` - The variants along with their string representation
+    pub fn generate_str_template(
+        &self,
+        target_ident: Ident,
+        transform_fields: Vec<TokenStream>,
+        values: Vec<(Ident, LitStr)>,
+    ) -> TokenStream {
+        let error_kind_ident = self.error_type();
+        let unknown_error = self.generate_unknown_error(quote! { value });
+        let (variants, names): (Vec<Ident>, Vec<LitStr>) = values.into_iter().unzip();
+        let cfg = self.cfg_attr();
+
+        quote! {
+            #cfg
+            impl ::core::str::FromStr for #target_ident {
+                type Err = #error_kind_ident;
+
+                fn from_str(arg: &str) -> Result<Self, Self::Err> {
+                    Ok(match arg {
+                        #(#transform_fields,)*
+                        value => return #unknown_error
+                    })
+                }
+            }
+
+            #cfg
+            impl TryFrom<&str> for #target_ident {
+                type Error = #error_kind_ident;
+
+                fn try_from(arg: &str) -> Result<Self, Self::Error> {
+                    <Self as ::core::str::FromStr>::from_str(arg)
+                }
+            }
+
+            #cfg
+            impl From<#target_ident> for &'static str {
+                fn from(arg: #target_ident) -> Self {
+                    match arg {
+                        #(#target_ident::#variants => #names),*
+                    }
+                }
+            }
+
+            #cfg
+            impl ::core::fmt::Display for #target_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(match self {
+                        #(#target_ident::#variants => #names),*
+                    })
+                }
+            }
+        }
+    }
+
+    /// Create the template of the method which assigns the source onto an existing target e.g:
+    /// - Patch: `fn apply(&mut self, patch: Source)` along with `impl BrickeMerge<Source>`
+    /// - Update: `fn update_from(&mut self, src: Source)` along with `impl BrickeUpdate<Source>`
+    ///
+    /// The method returns a Result when a try_error_kind is provided.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target struct path
+    /// * `statements` - The statements overwriting each target field
+    pub fn generate_assign_template(
+        &self,
+        target_ident: Path,
+        statements: Vec<TokenStream>,
+    ) -> TokenStream {
+        let source = match self.require_source() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let (output, ok, error, delegate) = match &self.error_kind {
+            Some(_) => {
+                let error_kind_ident = self.error_type();
+                (
+                    quote! { -> Result<(), #error_kind_ident> },
+                    quote! { Ok(()) },
+                    quote! { #error_kind_ident },
+                    quote! {},
+                )
+            }
+            None => (
+                quote! {},
+                quote! {},
+                quote! { ::core::convert::Infallible },
+                quote! { ; Ok(()) },
+            ),
+        };
+
+        let krate = self.krate();
+        let (method, trait_path, trait_method) = match self.converter {
+            ConverterType::Update => (
+                self.method_name("update_from"),
+                quote! { #krate::BrickeUpdate },
+                quote! { update },
+            ),
+            _ => (
+                self.method_name("apply"),
+                quote! { #krate::BrickeMerge },
+                quote! { merge },
+            ),
+        };
+        let cfg = self.cfg_attr();
+        let vis = self.visibility();
+
+        quote! {
+            #cfg
+            impl #target_ident {
+                #vis fn #method(&mut self, arg: #source) #output {
+                    #(#statements)*
+                    #ok
+                }
+            }
+
+            #cfg
+            impl #trait_path<#source> for #target_ident {
+                type Error = #error;
+
+                fn #trait_method(&mut self, source: #source) -> Result<(), Self::Error> {
+                    Self::#method(self, source) #delegate
+                }
+            }
+        }
+    }
+
+    /// Create the async conversion template e.g: `async fn from_source(src: Source) -> Result<Self, E>`.
+    /// The futures of the concurrent fields are polled together before building the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target struct path
+    /// * `bindings` - The statements binding the source before building the target
+    /// * `futures` - The futures running concurrently along with the binding holding their output
+    /// * `transform_fields` - The transformed fields
+    pub fn generate_async_template(
+        &self,
+        target_ident: Path,
+        bindings: TokenStream,
+        futures: Vec<(Ident, TokenStream)>,
+        transform_fields: Vec<TokenStream>,
+    ) -> TokenStream {
+        let source = match self.require_source() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        if let Some(error) = self.validate_error() {
+            return error;
+        }
+
+        let fields = quote! {
+            Self {
+                #(#transform_fields),*
+            }
+        };
+
+        let (output, body) = match &self.error_kind {
+            Some(_) => {
+                let error_kind_ident = self.error_type();
+                (
+                    quote! { Result<Self, #error_kind_ident> },
+                    self.generate_body(&fields, true),
+                )
+            }
+            None => (quote! { Self }, self.generate_body(&fields, false)),
+        };
+
+        let method = self.method_name("from_source");
+        let before = self.generate_before(self.error_kind.is_some());
+        let join = match futures.is_empty() {
+            true => quote! {},
+            false => generate_join(futures),
+        };
+        let cfg = self.cfg_attr();
+        let vis = self.visibility();
+
+        quote! {
+            #cfg
+            impl #target_ident {
+                #vis async fn #method(arg: #source) -> #output {
+                    #before
+                    #bindings
+                    #join
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Create the conversion template for the target item (struct or enum)
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target struct path
+    /// * `bindings` - The statements binding the source before building the target
+    /// * `transform_fields` - The transformed fields
+    /// * `supported_type` - The supported type of the target
+    pub fn generate_conversion_template(
+        &self,
+        target_ident: Path,
+        bindings: TokenStream,
+        transform_fields: Vec<TokenStream>,
+        supported_type: SupportedType,
+    ) -> TokenStream {
+        let source = match self.require_source() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        // Generate the conversion template for the list of fields that has been transformed
+        let fields = match supported_type {
+            SupportedType::Struct => quote! {
+                Self {
+                    #(#transform_fields),*
+                }
+            },
+            // A single variant of the target enum is built from the source struct
+            SupportedType::Variant(variant) => quote! {
+                Self::#variant {
+                    #(#transform_fields),*
+                }
+            },
+            // In the case of the enum we want to use the match expression to convert the source enum to the target enum
+            SupportedType::Enum => quote! {
+                match arg {
+                    #(#transform_fields),*
+                }
+            },
+        };
+
+        if let Some(error) = self.validate_error() {
+            return error;
+        }
+
+        let cfg = self.cfg_attr();
+        let vis = self.visibility();
+        let (before, body) = (
+            self.generate_before(false),
+            self.generate_body(&fields, false),
+        );
+        let body = quote! { #before #bindings #body };
+        let (before, ok) = (
+            self.generate_before(true),
+            self.generate_body(&fields, true),
+        );
+        let ok = quote! { #before #bindings #ok };
+        let conversion = match &self.converter {
+            ConverterType::From => {
+                quote! {
+                    #cfg
+                    impl From<#source> for #target_ident {
+                        fn from(arg: #source) -> Self {
+                            #body
+                        }
+                    }
+                }
+            }
+            ConverterType::TryFrom => {
+                let error_kind_ident = self.error_type();
+
+                quote! {
+                    #cfg
+                    impl TryFrom<#source> for #target_ident {
+                        type Error = #error_kind_ident;
+
+                        fn try_from(arg: #source) -> Result<Self, Self::Error> {
+                            #ok
+                        }
+                    }
+                }
+            }
+            ConverterType::FromStr => {
+                return self
+                    .converter_error("FromStr converter is only supported by fieldless enums");
+            }
+            ConverterType::Patch | ConverterType::Update | ConverterType::Async => {
+                return self.converter_error(
+                    "Patch, Update and Async converters are only supported by structs",
+                );
+            }
+            ConverterType::Custom(converter) => {
+                let Some(method) = &self.method else {
+                    return self.converter_error(&custom_converter_message(converter));
+                };
+
+                let trait_path = trait_path(converter, source);
+
+                match &self.error_kind {
+                    Some(_) => {
+                        let error_kind_ident = self.error_type();
+
+                        quote! {
+                            #cfg
+                            impl #trait_path for #target_ident {
+                                type Error = #error_kind_ident;
+
+                                fn #method(arg: #source) -> Result<Self, Self::Error> {
+                                    #ok
+                                }
+                            }
+                        }
+                    }
+                    None => quote! {
+                        #cfg
+                        impl #trait_path for #target_ident {
+                            fn #method(arg: #source) -> Self {
+                                #body
+                            }
+                        }
+                    },
+                }
+            }
+            ConverterType::FromWith => {
+                let method = self.method_name("from_source");
+                let Some(context) = &self.context else {
+                    return self.converter_error(
+                        "Expect a context to be provided along with FromWith e.g: `context = \"Ctx\"`",
+                    );
+                };
+
+                let (output, body, error, delegate) = match &self.error_kind {
+                    Some(_) => {
+                        let error_kind_ident = self.error_type();
+                        (
+                            quote! { Result<Self, #error_kind_ident> },
+                            ok,
+                            quote! { #error_kind_ident },
+                            quote! { Self::#method(source, ctx) },
+                        )
+                    }
+                    None => (
+                        quote! { Self },
+                        body,
+                        quote! { ::core::convert::Infallible },
+                        quote! { Ok(Self::#method(source, ctx)) },
+                    ),
+                };
+
+                let krate = self.krate();
+
+                quote! {
+                    #cfg
+                    impl #target_ident {
+                        #[allow(unused_variables)]
+                        #vis fn #method(arg: #source, ctx: &#context) -> #output {
+                            #body
+                        }
+                    }
+
+                    #cfg
+                    impl #krate::BrickeFromWith<#source, #context> for #target_ident {
+                        type Error = #error;
+
+                        fn from_with(source: #source, ctx: &#context) -> Result<Self, Self::Error> {
+                            #delegate
+                        }
+                    }
+                }
+            }
+        };
+
+        let methods = self.generate_methods_template(&target_ident, source);
+
+        quote! {
+            #conversion
+            #methods
+        }
+    }
+
+    /// Create the implementation of `BrickeMapping` exposing the mapping of each target field when the conversion
+    /// is described e.g: `impl ::bricke::BrickeMapping<Source> for Target`
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target struct path
+    /// * `descriptors` - The descriptor of each target field
+    pub fn generate_mapping_template(
+        &self,
+        target_ident: &Path,
+        descriptors: Vec<TokenStream>,
+    ) -> TokenStream {
+        if !self.is_described() {
+            return quote! {};
+        }
+
+        let source = match self.require_source() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let source_str = quote! { #source }.to_string().replace(' ', "");
+        let krate = self.krate();
+        let cfg = self.cfg_attr();
+
+        quote! {
+            #cfg
+            impl #krate::BrickeMapping<#source> for #target_ident {
+                const SOURCE: &'static str = #source_str;
+                const FIELDS: &'static [#krate::FieldMapping] = &[#(#descriptors),*];
+            }
+        }
+    }
+
+    /// Create the statement destructuring the source into a local binding for each consumed field e.g:
+    ///    - let Source { name: __bricke_arg_name, .. } = arg;
+    ///
+    /// When the conversion is strict, the pattern doesn't use `..` so that the compiler rejects any source field
+    /// which is neither consumed nor ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `consumed` - The source fields consumed by the target
+    pub fn generate_bindings(&self, consumed: Vec<Ident>) -> TokenStream {
+        let source = match self.source_pattern_path() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+        let consumed = dedup(consumed);
+        let bindings = consumed.iter().map(fields::source_binding);
+        let ignored = self.ignored_fields(&consumed);
+        let rest = (!self.strict).then(|| quote! { .. });
+
+        // The pattern is spanned at the source so that the compiler reports the missing fields
+        quote_spanned! {source.span()=>
+            let #source { #(#consumed: #bindings,)* #(#ignored: _,)* #rest } = arg;
+        }
+    }
+
+    /// Create the statement checking that every field of the source is consumed when the conversion is strict e.g:
+    ///    - let Source { name: _, email: _ } = &arg;
+    ///
+    /// # Arguments
+    ///
+    /// * `consumed` - The source fields consumed by the target
+    pub fn generate_strict_check(&self, consumed: Vec<Ident>) -> TokenStream {
+        if !self.strict {
+            return quote! {};
+        }
+
+        let source = match self.source_pattern_path() {
+            Ok(source) => source,
+            Err(err) => return err.to_compile_error(),
+        };
+        let consumed = dedup(consumed);
+        let ignored = self.ignored_fields(&consumed);
+
+        quote_spanned! {source.span()=>
+            let #source { #(#consumed: _,)* #(#ignored: _,)* } = &arg;
+        }
+    }
+
+    /// Get the source fields listed in ignore_source which aren't consumed, only a strict conversion lists them
+    fn ignored_fields(&self, consumed: &[Ident]) -> Vec<Ident> {
+        if !self.strict {
+            return Vec::new();
+        }
+
+        let ignored = self
+            .ignore_source
+            .iter()
+            .map(|field| Ident::new(&field.value(), field.span()))
+            .filter(|field| !consumed.contains(field))
+            .collect();
+
+        dedup(ignored)
+    }
+
+    /// Get the path of the source used in a pattern. The generic arguments need the turbofish syntax
+    /// e.g: `Page::<User> { .. }`
+    fn source_pattern_path(&self) -> Result<Path> {
+        let mut source = self.require_source()?.clone();
+        for segment in source.segments.iter_mut() {
+            if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token.get_or_insert_default();
+            }
+        }
+
+        Ok(source)
+    }
+
+    /// Create the statement passing the source to the before function if there is any e.g:
+    ///    - let arg = before(arg)?;
+    ///
+    /// # Arguments
+    ///
+    /// * `fallible` - Whether the conversion returns a Result
+    fn generate_before(&self, fallible: bool) -> TokenStream {
+        match (&self.before, fallible) {
+            (Some(before), true) => quote! { let arg = #before(arg)?; },
+            (Some(before), false) => quote! { let arg = #before(arg); },
+            (None, _) => quote! {},
+        }
+    }
+
+    /// Create the body returning the built target e.g:
+    ///    - let mut value = Self { ... }; after(&mut value); validate(&value)?; Ok(value)
+    ///
+    /// The target is validated only by a fallible conversion.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The expression building the target
+    /// * `fallible` - Whether the conversion returns a Result
+    fn generate_body(&self, fields: &TokenStream, fallible: bool) -> TokenStream {
+        let after = self
+            .after
+            .as_ref()
+            .map(|after| quote! { #after(&mut value); });
+
+        let validate = self
+            .validate
+            .as_ref()
+            .filter(|_| fallible)
+            .map(|validate| quote! { #validate(&value)?; });
+
+        let output = match fallible {
+            true => quote! { Ok(value) },
+            false => quote! { value },
+        };
+
+        let binding = match after {
+            Some(_) => quote! { let mut value },
+            None => quote! { let value },
+        };
+
+        match (&after, &validate) {
+            (None, None) if fallible => quote! { Ok(#fields) },
+            (None, None) => fields.clone(),
+            _ => quote! {
+                #binding = #fields;
+                #after
+                #validate
+                #output
+            },
+        }
+    }
+
+    /// Create the compile error of a validate function used along with a conversion which can't fail
+    fn validate_error(&self) -> Option<TokenStream> {
+        let validate = self.validate.as_ref()?;
+        if self.is_fallible() {
+            return None;
+        }
+
+        Some(
+            syn::Error::new(
+                validate.span(),
+                "Expect a fallible conversion along with validate e.g: `converter = \"TryFrom\"`",
+            )
+            .to_compile_error(),
+        )
+    }
+
+    /// Get the source of the conversion, a missing source is reported on the converter
+    pub fn require_source(&self) -> Result<&Path> {
+        self.source.as_ref().ok_or_else(|| {
+            syn::Error::new(
+                self.converter_span.unwrap_or_else(Span::call_site),
+                "Expect a source to be provided e.g: `source = \"Source\"`",
+            )
+        })
+    }
+
+    /// Check whether the mapping of the fields is exposed at runtime e.g: `describe = true`
+    pub fn is_described(&self) -> bool {
+        self.describe.as_ref().is_some_and(LitBool::value)
+    }
+
+    /// Create the compile error of `describe = true` set on an enum, only the fields of a struct are described
+    pub fn describe_error(&self) -> Option<TokenStream> {
+        let describe = self.describe.as_ref().filter(|describe| describe.value())?;
+
+        Some(
+            syn::Error::new(
+                describe.span(),
+                "describe is only supported by structs, the variants of an enum aren't described",
+            )
+            .to_compile_error(),
+        )
+    }
+
+    /// Create the compile error of a converter which can't be used on the item, the error is reported on the
+    /// converter e.g: `converter = "FromStr"` on a struct
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The error message
+    fn converter_error(&self, message: &str) -> TokenStream {
+        syn::Error::new(self.converter_span.unwrap_or_else(Span::call_site), message)
+            .to_compile_error()
+    }
+
+    /// Check whether the conversion can fail, either with `TryFrom` or along with an error kind
+    pub fn is_fallible(&self) -> bool {
+        matches!(self.converter, ConverterType::TryFrom) || self.error_kind.is_some()
+    }
+
+    /// Get the path of the runtime crate, `::bricke` is used when no crate is provided
+    pub fn krate(&self) -> Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| syn::parse_quote!(::bricke))
+    }
+
+    /// Get the name of the generated method, the default name is used when no method is provided
+    fn method_name(&self, default: &str) -> Ident {
+        self.method
+            .clone()
+            .unwrap_or_else(|| Ident::new(default, Span::call_site()))
+    }
+
+    /// Create the `#[cfg(...)]` attribute set on every generated item, nothing is created without a cfg
+    pub fn cfg_attr(&self) -> TokenStream {
+        match &self.cfg {
+            Some(cfg) => quote! { #[cfg(#cfg)] },
+            None => quote! {},
+        }
+    }
+
+    /// Get the visibility of the generated inherent methods, the methods are public by default
+    fn visibility(&self) -> TokenStream {
+        match &self.vis {
+            Some(vis) => quote! { #vis },
+            None => quote! { pub },
+        }
+    }
+
+    /// Create the inherent methods delegating to the conversion trait e.g:
+    /// - method: `impl Target { pub fn from_db(src: Source) -> Self }`
+    /// - into_method: `impl Source { pub fn into_dto(self) -> Target }`
+    ///
+    /// # Arguments
+    ///
+    /// * `target_ident` - The target path
+    /// * `source` - The source path
+    fn generate_methods_template(&self, target_ident: &Path, source: &Path) -> TokenStream {
+        let error_output = |target: TokenStream| match &self.error_kind {
+            Some(_) => {
+                let error_kind_ident = self.error_type();
+                quote! { Result<#target, #error_kind_ident> }
+            }
+            None => target,
+        };
+
+        let (call, output) = match &self.converter {
+            ConverterType::From => (
+                quote! { <#target_ident as From<#source>>::from },
+                quote! { #target_ident },
+            ),
+            ConverterType::TryFrom => (
+                quote! { <#target_ident as TryFrom<#source>>::try_from },
+                quote! { Result<#target_ident, <#target_ident as TryFrom<#source>>::Error> },
+            ),
+            ConverterType::Custom(converter) => {
+                let method = self.method_name("from");
+                let trait_path = trait_path(converter, source);
+                (
+                    quote! { <#target_ident as #trait_path>::#method },
+                    error_output(quote! { #target_ident }),
+                )
+            }
+            _ => return quote! {},
+        };
+
+        let cfg = self.cfg_attr();
+        let vis = self.visibility();
+
+        // The method of a custom converter is the method of the trait
+        let constructor = match (&self.converter, &self.method) {
+            (ConverterType::Custom(_), _) | (_, None) => quote! {},
+            (_, Some(method)) => quote! {
+                #cfg
+                impl #target_ident {
+                    #vis fn #method(arg: #source) -> #output {
+                        #call(arg)
+                    }
+                }
+            },
+        };
+
+        let into = match &self.into_method {
+            Some(into_method) => quote! {
+                #cfg
+                impl #source {
+                    #vis fn #into_method(self) -> #output {
+                        #call(self)
+                    }
+                }
+            },
+            None => quote! {},
+        };
+
+        quote! {
+            #constructor
+            #into
+        }
+    }
+}
+
+/// Remove the duplicated identifiers while keeping their order
+fn dedup(idents: Vec<Ident>) -> Vec<Ident> {
+    let mut unique: Vec<Ident> = Vec::with_capacity(idents.len());
+    for ident in idents {
+        if !unique.contains(&ident) {
+            unique.push(ident);
+        }
+    }
+
+    unique
+}
+
+/// Parse a list of field names e.g: `omit = ["password", "token"]`
+fn parse_field_names(meta: ParseNestedMeta) -> Result<Vec<LitStr>> {
+    let names: ExprArray = meta.value()?.parse()?;
+    names
+        .elems
+        .into_iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(field),
+                ..
+            }) => Ok(field),
+            _ => Err(syn::Error::new(elem.span(), "Expect a field name")),
+        })
+        .collect()
+}
+
+/// Create the error message of a custom converter provided without its method. A converter matching a built-in
+/// converter regardless of the case is most likely a typo e.g: `converter = "from"`
+///
+/// # Arguments
+///
+/// * `converter` - The path of the custom trait
+fn custom_converter_message(converter: &Path) -> String {
+    let name = quote! { #converter }.to_string().replace(' ', "");
+    let message = format!(
+        "Expect a method to be provided along with the custom converter `{}` e.g: `method = \"from_proto\"`",
+        name
+    );
+
+    match BUILTIN_CONVERTERS
+        .iter()
+        .find(|builtin| builtin.eq_ignore_ascii_case(&name))
+    {
+        Some(builtin) => format!("{}, or did you mean `{}`?", message, builtin),
+        None => message,
+    }
+}
+
+/// Create the path of a custom trait. The trait is generic over the source unless the generic arguments are
+/// already provided e.g: `converter = "FromProto<proto::User>"`
+fn trait_path(converter: &Path, source: &Path) -> TokenStream {
+    match converter.segments.last().map(|seg| &seg.arguments) {
+        Some(syn::PathArguments::None) | None => quote! { #converter<#source> },
+        Some(_) => quote! { #converter },
+    }
+}
+
+/// Create the statements polling the futures together until all of them are completed. The output of each
+/// future is then stored in its binding.
+///
+/// # Arguments
+///
+/// * `futures` - The futures along with the binding holding their output
+fn generate_join(futures: Vec<(Ident, TokenStream)>) -> TokenStream {
+    let (bindings, futures): (Vec<Ident>, Vec<TokenStream>) = futures.into_iter().unzip();
+    let pinned: Vec<Ident> = bindings
+        .iter()
+        .map(|binding| format_ident!("{}_future", binding))
+        .collect();
+    let outputs: Vec<Ident> = bindings
+        .iter()
+        .map(|binding| format_ident!("{}_output", binding))
+        .collect();
+
+    quote! {
+        #(
+            let mut #pinned = ::core::pin::pin!(#futures);
+            let mut #outputs = ::core::option::Option::None;
+        )*
+
+        ::core::future::poll_fn(|cx| {
+            let mut ready = true;
+            #(
+                if #outputs.is_none() {
+                    match ::core::future::Future::poll(#pinned.as_mut(), cx) {
+                        ::core::task::Poll::Ready(output) => #outputs = ::core::option::Option::Some(output),
+                        ::core::task::Poll::Pending => ready = false,
+                    }
+                }
+            )*
+
+            match ready {
+                true => ::core::task::Poll::Ready(()),
+                false => ::core::task::Poll::Pending,
+            }
+        })
+        .await;
+
+        #(
+            let #bindings = #outputs.expect("Expect the future to be completed");
+        )*
+    }
+}
@@ -0,0 +1,177 @@
+use syn::{Ident, LitStr, Result};
+
+/// RenameRule is the case convention used by the source names. The target names are converted to this
+/// convention in order to find the matching source field or variant.
+///
+/// The supported conventions are the following:
+/// - lowercase
+/// - UPPERCASE
+/// - PascalCase
+/// - camelCase
+/// - snake_case
+/// - SCREAMING_SNAKE_CASE
+/// - kebab-case
+/// - SCREAMING-KEBAB-CASE
+#[derive(Clone, Copy, PartialEq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Parse the rename rule from a literal string e.g: `rename_all = "camelCase"`
+    ///
+    /// # Arguments
+    ///
+    /// * `lit` - The literal string containing the case convention
+    pub fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(RenameRule::ScreamingKebab),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "Unknown rename_all case convention",
+            )),
+        }
+    }
+
+    /// Apply the case convention to the given name. The name can either be in snake_case (fields) or PascalCase (variants)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to convert
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(idx, w)| match idx {
+                    0 => w.to_lowercase(),
+                    _ => capitalize(w),
+                })
+                .collect(),
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-").to_lowercase(),
+            RenameRule::ScreamingKebab => words.join("-").to_uppercase(),
+        }
+    }
+}
+
+/// Compute the source name of a target field or variant by applying the case convention and adding the prefix / suffix
+/// that have been stripped from the source names. A source name matching a keyword is a raw identifier e.g: `r#type`,
+/// any other invalid identifier e.g: `user-name` returns an error.
+///
+/// # Arguments
+///
+/// * `name` - The target name
+/// * `rule` - The case convention of the source
+/// * `prefix` - The prefix carried by the source names
+/// * `suffix` - The suffix carried by the source names
+pub fn source_name(
+    name: &Ident,
+    rule: Option<RenameRule>,
+    prefix: Option<&LitStr>,
+    suffix: Option<&LitStr>,
+) -> Result<Ident> {
+    if rule.is_none() && prefix.is_none() && suffix.is_none() {
+        return Ok(name.clone());
+    }
+
+    let full_name = source_str(name, rule, prefix, suffix);
+
+    syn::parse_str::<Ident>(&full_name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{full_name}")))
+        .map_err(|_| {
+            syn::Error::new(
+                name.span(),
+                format!("Renamed source `{full_name}` of `{name}` is not a valid identifier, use `rename` instead"),
+            )
+        })
+}
+
+/// Same as [`source_name`] but the result is kept as a string which allows any case convention to be used
+/// e.g: `kebab-case` for a string representation of a variant
+pub fn source_str(
+    name: &Ident,
+    rule: Option<RenameRule>,
+    prefix: Option<&LitStr>,
+    suffix: Option<&LitStr>,
+) -> String {
+    let raw = name.to_string();
+    let raw = raw.trim_start_matches("r#");
+    let converted = match rule {
+        Some(rule) => rule.apply(raw),
+        None => raw.to_string(),
+    };
+
+    format!(
+        "{}{}{}",
+        prefix.map(LitStr::value).unwrap_or_default(),
+        converted,
+        suffix.map(LitStr::value).unwrap_or_default()
+    )
+}
+
+/// Split a name into words. A word ends at an underscore, a dash or before an uppercase letter starting a new word
+/// e.g: `user_name` => [user, name], `HTTPServer` => [HTTP, Server]
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (idx, c) in chars.iter().enumerate() {
+        if *c == '_' || *c == '-' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let prev_lower = chars[idx - 1].is_lowercase() || chars[idx - 1].is_numeric();
+            let next_lower = chars.get(idx + 1).is_some_and(|n| n.is_lowercase());
+            let prev_upper = chars[idx - 1].is_uppercase();
+
+            if prev_lower || (prev_upper && next_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(*c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,248 @@
+use super::*;
+use syn::Path;
+
+impl BrickeFieldArgs {
+    /// Create the enum template which will be used inside the field to map the path src: target within a match statement.
+    /// This will create an enum value for "each statement" e.g:
+    ///    - Source::Foo => Target::Foo
+    ///
+    /// A unit target variant accepts any source variant shape e.g: `Source::Foo(..) => Target::Foo`. A data-carrying
+    /// target variant can be created from a unit source variant by using `from_unit = true`, the payload is then filled
+    /// with default values or with the output of the transform function.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the enum template.
+    /// * `source` - The source of the enum template.
+    /// * `source_name` - The name of the source variant when the variant isn't renamed.
+    /// * `fields` - The fields of the enum template.
+    /// * `enum_fields` - The fields of the target variant.
+    /// * `krate` - The path of the runtime crate.
+    pub fn create_enum_template(
+        name: Ident,
+        source: Option<Path>,
+        source_name: Ident,
+        fields: Vec<Self>,
+        enum_fields: EnumInnerFields,
+        krate: &Path,
+    ) -> TokenStream {
+        let mut rename: Option<Ident> = Some(source_name);
+        let mut to_skip = false;
+        let mut from_unit = false;
+        let mut rename_all: Option<RenameRule> = None;
+        let mut f: Option<Path> = None;
+
+        for field in fields {
+            if let Self::Rename(rename_field) = field.to_owned() {
+                rename = Some(Ident::new(&rename_field.value(), Span::call_site()));
+            }
+
+            if let Self::Exclude(e) = field.to_owned()
+                && e.value()
+            {
+                to_skip = true;
+            }
+
+            if let Self::FromUnit(u) = field.to_owned() {
+                from_unit = u.value();
+            }
+
+            if let Self::RenameAll(rule) = field.to_owned() {
+                rename_all = Some(rule);
+            }
+
+            if let Self::ConvertFieldFn(fn_field) | Self::Transform(fn_field) = field.to_owned() {
+                f = fn_field
+                    .parse_with(syn::Path::parse_mod_style)
+                    .map_err(|_| syn::Error::new(fn_field.span(), ERROR_PARSE_FN))
+                    .ok();
+            }
+
+            if let Self::BuiltinTransform(builtin) = field.to_owned() {
+                f = Some(builtin_transform(&builtin, krate));
+            }
+        }
+
+        if to_skip {
+            return quote! {};
+        }
+
+        // The source variant doesn't carry any data, the payload of the target variant is either built
+        // by the transform function or filled with the default values.
+        if from_unit {
+            return enum_builder::generate_from_unit(source, name, rename, f, &enum_fields);
+        }
+
+        let source_pattern = enum_fields.source_pattern(rename_all);
+
+        match f {
+            Some(f) => enum_builder::generate_enum_fn(
+                source,
+                name,
+                rename,
+                f,
+                &enum_fields,
+                source_pattern,
+            ),
+            None => match enum_fields {
+                // A unit target variant ignores the payload of the source variant if there is any
+                EnumInnerFields::Unit => {
+                    quote! {
+                        #source::#rename { .. } => Self::#name
+                    }
+                }
+                _ => {
+                    let pattern = enum_fields.pattern();
+
+                    quote! {
+                        #source::#rename #source_pattern => Self::#name #pattern
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl BrickeFieldArgs {
+    /// Create the match arm converting an integer source to a fieldless variant e.g:
+    ///    - value if value == 3 => Target::Foo
+    ///
+    /// Returns the match arm (if the variant isn't excluded) along with the discriminant of the variant. A `value`
+    /// set on the variant takes precedence over the discriminant.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variant.
+    /// * `fields` - The fields of the variant.
+    /// * `discriminant` - The discriminant of the variant.
+    pub fn create_discriminant_template(
+        name: Ident,
+        fields: Vec<Self>,
+        discriminant: TokenStream,
+    ) -> (Option<TokenStream>, TokenStream) {
+        let mut value = discriminant;
+        let mut to_skip = false;
+
+        for field in fields {
+            if let Self::Value(v) = field.to_owned() {
+                value = quote! { #v };
+            }
+
+            if let Self::Exclude(e) = field.to_owned()
+                && e.value()
+            {
+                to_skip = true;
+            }
+        }
+
+        let arm = match to_skip {
+            true => None,
+            false => Some(quote! {
+                value if value == (#value) => Self::#name
+            }),
+        };
+
+        (arm, value)
+    }
+}
+
+impl BrickeFieldArgs {
+    /// Create the match arm converting a string to a fieldless variant e.g:
+    ///    - "foo" | "f" => Target::Foo
+    ///
+    /// Returns the match arm (if the variant isn't excluded) along with the string representation of the variant.
+    /// A `rename` set on the variant takes precedence over the computed name, and each `alias` is accepted as well.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the variant.
+    /// * `source_str` - The string representation of the variant when the variant isn't renamed.
+    /// * `fields` - The fields of the variant.
+    /// * `case_insensitive` - Whether the string is matched regardless of the case.
+    pub fn create_str_template(
+        name: Ident,
+        source_str: String,
+        fields: Vec<Self>,
+        case_insensitive: bool,
+    ) -> (Option<TokenStream>, LitStr) {
+        let mut repr = LitStr::new(&source_str, name.span());
+        let mut aliases = Vec::new();
+        let mut to_skip = false;
+
+        for field in fields {
+            if let Self::Rename(r) = field.to_owned() {
+                repr = r;
+            }
+
+            if let Self::Alias(alias) = field.to_owned() {
+                aliases.push(alias);
+            }
+
+            if let Self::Exclude(e) = field.to_owned()
+                && e.value()
+            {
+                to_skip = true;
+            }
+        }
+
+        if to_skip {
+            return (None, repr);
+        }
+
+        let accepted = std::iter::once(&repr).chain(aliases.iter());
+        let arm = match case_insensitive {
+            true => quote! {
+                value if #(value.eq_ignore_ascii_case(#accepted))||* => Self::#name
+            },
+            false => quote! {
+                #(#accepted)|* => Self::#name
+            },
+        };
+
+        (Some(arm), repr)
+    }
+}
+
+mod enum_builder {
+    use super::*;
+
+    pub fn generate_enum_fn(
+        source: Option<Path>,
+        original_field_name: Ident,
+        rename: Option<Ident>,
+        fn_tmpl: Path,
+        enum_inner_fields: &EnumInnerFields,
+        pattern: TokenStream,
+    ) -> TokenStream {
+        match enum_inner_fields {
+            EnumInnerFields::Unnamed(idents) => quote! {
+                #source::#rename #pattern => Self::#original_field_name(#fn_tmpl(#(#idents),*))
+            },
+            EnumInnerFields::Named(idents) => quote! {
+                #source::#rename #pattern => #fn_tmpl (#(#idents),*)
+            },
+            // The source variant is passed as is to the transform function whatever its payload is
+            EnumInnerFields::Unit => quote! {
+                src @ #source::#rename { .. } => #fn_tmpl (src)
+            },
+        }
+    }
+
+    pub fn generate_from_unit(
+        source: Option<Path>,
+        original_field_name: Ident,
+        rename: Option<Ident>,
+        fn_tmpl: Option<Path>,
+        enum_inner_fields: &EnumInnerFields,
+    ) -> TokenStream {
+        let complete_fn_call = match (fn_tmpl, enum_inner_fields) {
+            (Some(f), EnumInnerFields::Unnamed(_)) => quote! { Self::#original_field_name(#f()) },
+            (Some(f), _) => quote! { #f() },
+            (None, _) => {
+                let payload = enum_inner_fields.default_payload();
+                quote! { Self::#original_field_name #payload }
+            }
+        };
+
+        quote! {
+            #source::#rename => #complete_fn_call
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::case::RenameRule;
+use crate::item::enum_item::EnumInnerFields;
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Expr, ExprArray, ExprLit, Ident, Lit, LitBool, LitStr, Result, Token, ext::IdentExt,
+    parse::Parse, parse::ParseStream,
+};
+
+pub mod enums;
+pub mod structure;
+
+// Constants
+const ERROR_PARSE_FN: &str = "Expect a function call";
+
+/// BUILTIN_TRANSFORMS are the transforms of the runtime crate which can be used as a keyword e.g: `transform = trim`
+const BUILTIN_TRANSFORMS: [&str; 11] = [
+    "trim",
+    "lowercase",
+    "uppercase",
+    "empty_to_none",
+    "none_if_empty",
+    "secs_to_system_time",
+    "system_time_to_secs",
+    "millis_to_duration",
+    "duration_to_millis",
+    "dedup",
+    "sort",
+];
+
+/// NUMERIC_MODES are the conversions between integer types which can be used with `numeric = "..."`
+const NUMERIC_MODES: [&str; 3] = ["checked", "saturating", "wrapping"];
+
+/// TIME_FORMATS are the representations of a jiff timestamp which can be used with `time = "..."`
+const TIME_FORMATS: [&str; 3] = ["epoch_seconds", "epoch_millis", "rfc3339"];
+
+#[derive(Clone)]
+pub enum BrickeFieldArgs {
+    ConvertFieldFn(LitStr),
+    Rename(LitStr),
+    Exclude(LitBool),
+    IsFallible(LitBool),
+    FromUnit(LitBool),
+    RenameAll(RenameRule),
+    Value(Expr),
+    Alias(LitStr),
+    WithCtx(LitBool),
+    IsAsync(LitBool),
+    Validate(LitStr),
+    Message(LitStr),
+    Sources(Vec<LitStr>),
+    Borrow(LitBool),
+    Into(LitBool),
+    Required(LitBool),
+    Transform(LitStr),
+    BuiltinTransform(Ident),
+    Numeric(LitStr),
+    Time(LitStr),
+}
+
+impl Parse for BrickeFieldArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let keyword: Ident = input.parse()?;
+        let _eq_token: Token![=] = input.parse()?;
+
+        match keyword {
+            k if k == "transform_fn" => Ok(BrickeFieldArgs::ConvertFieldFn(input.parse()?)),
+            k if k == "rename" => Ok(BrickeFieldArgs::Rename(input.parse()?)),
+            k if k == "exclude" => Ok(BrickeFieldArgs::Exclude(input.parse()?)),
+            k if k == "is_fallible" => Ok(BrickeFieldArgs::IsFallible(input.parse()?)),
+            k if k == "from_unit" => Ok(BrickeFieldArgs::FromUnit(input.parse()?)),
+            k if k == "value" => Ok(BrickeFieldArgs::Value(input.parse()?)),
+            k if k == "alias" => Ok(BrickeFieldArgs::Alias(input.parse()?)),
+            k if k == "with_ctx" => Ok(BrickeFieldArgs::WithCtx(input.parse()?)),
+            k if k == "is_async" => Ok(BrickeFieldArgs::IsAsync(input.parse()?)),
+            k if k == "validate" => Ok(BrickeFieldArgs::Validate(input.parse()?)),
+            k if k == "message" => Ok(BrickeFieldArgs::Message(input.parse()?)),
+            k if k == "sources" => {
+                let sources: ExprArray = input.parse()?;
+                let sources = sources
+                    .elems
+                    .into_iter()
+                    .map(|elem| match elem {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(source),
+                            ..
+                        }) => Ok(source),
+                        _ => Err(syn::Error::new_spanned(elem, "Expect a field name")),
+                    })
+                    .collect::<Result<_>>()?;
+
+                Ok(BrickeFieldArgs::Sources(sources))
+            }
+            k if k == "borrow" => Ok(BrickeFieldArgs::Borrow(input.parse()?)),
+            k if k == "into" => Ok(BrickeFieldArgs::Into(input.parse()?)),
+            k if k == "required" => Ok(BrickeFieldArgs::Required(input.parse()?)),
+            k if k == "transform" => {
+                if input.peek(LitStr) {
+                    return Ok(BrickeFieldArgs::Transform(input.parse()?));
+                }
+
+                let builtin: Ident = input.parse()?;
+                if !BUILTIN_TRANSFORMS.contains(&builtin.to_string().as_str()) {
+                    return Err(syn::Error::new(
+                        builtin.span(),
+                        format!(
+                            "Unknown built-in transform, expect one of: {}",
+                            BUILTIN_TRANSFORMS.join(", ")
+                        ),
+                    ));
+                }
+
+                Ok(BrickeFieldArgs::BuiltinTransform(builtin))
+            }
+            k if k == "numeric" => {
+                let mode: LitStr = input.parse()?;
+                if !NUMERIC_MODES.contains(&mode.value().as_str()) {
+                    return Err(syn::Error::new(
+                        mode.span(),
+                        format!(
+                            "Unknown numeric conversion, expect one of: {}",
+                            NUMERIC_MODES.join(", ")
+                        ),
+                    ));
+                }
+
+                Ok(BrickeFieldArgs::Numeric(mode))
+            }
+            k if k == "time" => {
+                let format: LitStr = input.parse()?;
+                if !TIME_FORMATS.contains(&format.value().as_str()) {
+                    return Err(syn::Error::new(
+                        format.span(),
+                        format!(
+                            "Unknown time format, expect one of: {}",
+                            TIME_FORMATS.join(", ")
+                        ),
+                    ));
+                }
+
+                Ok(BrickeFieldArgs::Time(format))
+            }
+            k if k == "rename_all" => Ok(BrickeFieldArgs::RenameAll(RenameRule::parse(
+                &input.parse()?,
+            )?)),
+            _ => Err(syn::Error::new(keyword.span(), "Attribute not supported")),
+        }
+    }
+}
+
+/// Get the path of a built-in transform e.g: `transform = trim` refers to `::bricke::transforms::trim`
+///
+/// # Arguments
+///
+/// * `builtin` - The name of the built-in transform
+/// * `krate` - The path of the runtime crate
+pub(crate) fn builtin_transform(builtin: &Ident, krate: &syn::Path) -> syn::Path {
+    syn::parse_quote!(#krate::transforms::#builtin)
+}
+
+/// Get the name of the runtime type of a time format e.g: `epoch_millis` refers to `EpochMillis`
+///
+/// # Arguments
+///
+/// * `format` - The time format
+pub(crate) fn time_format(format: &str) -> String {
+    RenameRule::Pascal.apply(format)
+}
+
+/// Create the compile error of a field validated with `validate` or `message` along with a conversion which can't fail
+///
+/// # Arguments
+///
+/// * `fields` - The field attributes
+/// * `fallible` - Whether the conversion can fail
+pub(crate) fn validate_error(fields: &[BrickeFieldArgs], fallible: bool) -> Option<TokenStream> {
+    let validate = fields.iter().find_map(|field| match field {
+        BrickeFieldArgs::Validate(lit) | BrickeFieldArgs::Message(lit) => Some(lit),
+        _ => None,
+    })?;
+
+    match fallible {
+        true => None,
+        false => Some(
+            syn::Error::new(
+                validate.span(),
+                "Expect a fallible conversion along with a field validate e.g: `converter = \"TryFrom\"`",
+            )
+            .to_compile_error(),
+        ),
+    }
+}
+
+/// Create the compile error of a `numeric = "checked"` field used along with a conversion which can't fail
+///
+/// # Arguments
+///
+/// * `fields` - The field attributes
+/// * `fallible` - Whether the conversion can fail
+pub(crate) fn numeric_error(fields: &[BrickeFieldArgs], fallible: bool) -> Option<TokenStream> {
+    let checked = fields.iter().find_map(|field| match field {
+        BrickeFieldArgs::Numeric(mode) if mode.value() == "checked" => Some(mode),
+        _ => None,
+    })?;
+
+    match fallible {
+        true => None,
+        false => Some(
+            syn::Error::new(
+                checked.span(),
+                "Expect a fallible conversion along with `numeric = \"checked\"` e.g: `converter = \"TryFrom\"`, use `saturating` or `wrapping` otherwise",
+            )
+            .to_compile_error(),
+        ),
+    }
+}
+
+/// Get the local binding holding the value of a source field once the source is destructured e.g:
+/// `name` is bound to `__bricke_arg_name`
+pub(crate) fn source_binding(source_name: &Ident) -> Ident {
+    Ident::new(
+        &format!("__bricke_arg_{}", source_name.unraw()),
+        source_name.span(),
+    )
+}
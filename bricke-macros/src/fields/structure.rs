@@ -0,0 +1,604 @@
+use super::*;
+use quote::quote_spanned;
+use syn::{Path, Type, spanned::Spanned};
+
+/// StructField holds the options of a struct field collected from its `bricke_field` attributes
+struct StructField {
+    name: Ident,
+    from_field_name: Ident,
+    f: Option<Path>,
+    builtin: Option<Ident>,
+    to_skip: bool,
+    is_fallible: bool,
+    with_ctx: bool,
+    is_async: bool,
+    validate: Option<Path>,
+    message: Option<LitStr>,
+    sources: Vec<Ident>,
+    borrow: bool,
+    into: bool,
+    required: bool,
+    numeric: Option<String>,
+    time: Option<String>,
+    ty: Option<Type>,
+    krate: Option<Path>,
+    try_conversion: bool,
+}
+
+impl StructField {
+    fn new(name: Ident, source_name: Ident, fields: Vec<BrickeFieldArgs>) -> Self {
+        let mut field_opts = StructField {
+            name,
+            from_field_name: source_name,
+            f: None,
+            builtin: None,
+            to_skip: false,
+            is_fallible: false,
+            with_ctx: false,
+            is_async: false,
+            validate: None,
+            message: None,
+            sources: Vec::new(),
+            borrow: false,
+            into: false,
+            required: false,
+            numeric: None,
+            time: None,
+            ty: None,
+            krate: None,
+            try_conversion: false,
+        };
+
+        for field in fields {
+            if let BrickeFieldArgs::Rename(n) = field.to_owned() {
+                field_opts.from_field_name = Ident::new(&n.value(), Span::call_site());
+            }
+
+            if let BrickeFieldArgs::ConvertFieldFn(fn_str) | BrickeFieldArgs::Transform(fn_str) =
+                field.to_owned()
+            {
+                field_opts.f = fn_str
+                    .parse_with(syn::Path::parse_mod_style)
+                    .map_err(|_| syn::Error::new(fn_str.span(), ERROR_PARSE_FN))
+                    .ok();
+            }
+
+            if let BrickeFieldArgs::BuiltinTransform(builtin) = field.to_owned() {
+                field_opts.builtin = Some(builtin);
+            }
+
+            if let BrickeFieldArgs::IsFallible(r) = field.to_owned() {
+                field_opts.is_fallible = r.value();
+            }
+
+            if let BrickeFieldArgs::WithCtx(c) = field.to_owned() {
+                field_opts.with_ctx = c.value();
+            }
+
+            if let BrickeFieldArgs::IsAsync(a) = field.to_owned() {
+                field_opts.is_async = a.value();
+            }
+
+            if let BrickeFieldArgs::Validate(fn_str) = field.to_owned() {
+                field_opts.validate = fn_str
+                    .parse_with(syn::Path::parse_mod_style)
+                    .map_err(|_| syn::Error::new(fn_str.span(), ERROR_PARSE_FN))
+                    .ok();
+            }
+
+            if let BrickeFieldArgs::Message(m) = field.to_owned() {
+                field_opts.message = Some(m);
+            }
+
+            if let BrickeFieldArgs::Sources(sources) = field.to_owned() {
+                field_opts.sources = sources
+                    .iter()
+                    .map(|source| Ident::new(&source.value(), source.span()))
+                    .collect();
+            }
+
+            if let BrickeFieldArgs::Borrow(b) = field.to_owned() {
+                field_opts.borrow = b.value();
+            }
+
+            if let BrickeFieldArgs::Into(i) = field.to_owned() {
+                field_opts.into = i.value();
+            }
+
+            if let BrickeFieldArgs::Required(r) = field.to_owned() {
+                field_opts.required = r.value();
+            }
+
+            if let BrickeFieldArgs::Numeric(mode) = field.to_owned() {
+                field_opts.numeric = Some(mode.value());
+            }
+
+            if let BrickeFieldArgs::Time(format) = field.to_owned() {
+                field_opts.time = Some(format.value());
+            }
+
+            // In the case where we exclude the field, we just skip to output that field.
+            if let BrickeFieldArgs::Exclude(e) = field.to_owned()
+                && e.value()
+            {
+                field_opts.to_skip = true;
+            }
+        }
+
+        // The field is built from the renamed source field unless several source fields are provided
+        if field_opts.sources.is_empty() {
+            field_opts.sources.push(field_opts.from_field_name.clone());
+        }
+
+        field_opts
+    }
+
+    /// Get the path of the transform function if there is any. A built-in transform refers to the transforms of
+    /// the runtime crate.
+    fn transform(&self) -> Option<Path> {
+        match (&self.f, &self.builtin) {
+            (Some(f), _) => Some(f.clone()),
+            (None, Some(builtin)) => {
+                let krate = self
+                    .krate
+                    .as_ref()
+                    .expect("Expect the runtime crate to be provided");
+
+                Some(builtin_transform(builtin, krate))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Get the source fields consumed by the field, excluded fields don't consume any field
+    fn consumed(&self) -> Vec<Ident> {
+        match self.to_skip {
+            true => Vec::new(),
+            false => self.sources.clone(),
+        }
+    }
+
+    /// Create the value passed to the transform function from the bindings of the source fields e.g:
+    ///    - __bricke_arg_name
+    ///    - &__bricke_arg_firstname, &__bricke_arg_lastname
+    ///    - ::bricke::required(__bricke_arg_name, "name")?
+    ///
+    /// Without a transform function, several source fields are gathered into a tuple. A required source field
+    /// is unwrapped from its Option, a missing value returns a `BrickeError` converted into the error.
+    fn source_value(&self) -> TokenStream {
+        let bindings = self.sources.iter().map(|source| {
+            let binding = source_binding(source);
+
+            match (self.required, &self.krate, self.borrow) {
+                (true, Some(krate), borrow) => {
+                    let field = source.unraw().to_string();
+                    let value = match borrow {
+                        true => quote! { #binding.as_ref() },
+                        false => quote! { #binding },
+                    };
+
+                    quote! { #krate::required(#value, #field)? }
+                }
+                (_, _, true) => quote! { &#binding },
+                _ => quote! { #binding },
+            }
+        });
+
+        match (self.transform(), self.sources.len()) {
+            (None, len) if len > 1 => quote! { (#(#bindings),*) },
+            _ => quote! { #(#bindings),* },
+        }
+    }
+
+    /// Create the value of the field inside of the `Self` expression e.g: `name: __bricke_arg_name`
+    fn field_value(&self) -> TokenStream {
+        let name = &self.name;
+
+        match self.to_skip {
+            true => quote! {
+                #name: Default::default()
+            },
+            false => {
+                let value = self.convert(self.source_value());
+                quote! { #name: #value }
+            }
+        }
+    }
+
+    /// Call the transform function on the source value if there is any. The context is passed as the
+    /// second argument of the transform function when the field uses it.
+    fn call(&self, value: &TokenStream) -> Option<TokenStream> {
+        let args = match self.with_ctx {
+            true => quote! { #value, ctx },
+            false => value.clone(),
+        };
+
+        self.transform().map(|f| quote! { #f (#args) })
+    }
+
+    /// Apply the transform function on the source value if there is any. An async transform function
+    /// is awaited and a fallible one is propagated with `?`. The converted value is validated afterward.
+    fn convert(&self, value: TokenStream) -> TokenStream {
+        let Some(call) = self.call(&value) else {
+            return match (&self.numeric, &self.time) {
+                (None, None) => self.validated(self.assigned(value)),
+                _ => self.validated(self.numeric(self.time(value))),
+            };
+        };
+
+        let call = match self.is_async {
+            true => quote! { #call.await },
+            false => call,
+        };
+
+        match self.is_fallible {
+            true => self.validated(self.numeric(self.time(quote! { #call? }))),
+            false => self.validated(self.numeric(self.time(call))),
+        }
+    }
+
+    /// Check whether building the field can fail i.e: any of its options propagates an error with `?`. An excluded
+    /// field is built from its default value and never fails.
+    fn can_fail(&self) -> bool {
+        if self.to_skip {
+            return false;
+        }
+
+        self.is_fallible
+            || self.required
+            || self.validate.is_some()
+            || self.numeric.as_deref() == Some("checked")
+            || (self.time.is_some() && self.try_conversion)
+    }
+
+    /// Convert the value from or to a jiff timestamp when a time format is provided. The conversion can fail only
+    /// when the conversion is fallible e.g:
+    ///    - ::bricke::time::TimeFrom::<_, ::bricke::time::EpochMillis>::time_from(__bricke_arg_created_at)
+    ///    - ::bricke::time::TryTimeFrom::<_, ::bricke::time::Rfc3339>::try_time_from(__bricke_arg_created_at, "created_at")?
+    fn time(&self, value: TokenStream) -> TokenStream {
+        let (Some(format), Some(krate)) = (&self.time, &self.krate) else {
+            return value;
+        };
+
+        let format = Ident::new(&time_format(format), Span::call_site());
+        match self.try_conversion {
+            true => {
+                let field = self.name.unraw().to_string();
+                quote! {
+                    #krate::time::TryTimeFrom::<_, #krate::time::#format>::try_time_from(#value, #field)?
+                }
+            }
+            false => quote! {
+                #krate::time::TimeFrom::<_, #krate::time::#format>::time_from(#value)
+            },
+        }
+    }
+
+    /// Convert the value into the integer type of the target field when a numeric conversion is provided e.g:
+    ///    - checked: ::bricke::checked(__bricke_arg_id, "id")?
+    ///    - saturating: ::bricke::SaturatingFrom::saturating_from(__bricke_arg_id)
+    ///    - wrapping: ::bricke::WrappingFrom::wrapping_from(__bricke_arg_id)
+    fn numeric(&self, value: TokenStream) -> TokenStream {
+        let (Some(mode), Some(krate)) = (&self.numeric, &self.krate) else {
+            return value;
+        };
+
+        match mode.as_str() {
+            "checked" => {
+                let field = self.name.unraw().to_string();
+                quote! { #krate::checked(#value, #field)? }
+            }
+            "saturating" => quote! { #krate::SaturatingFrom::saturating_from(#value) },
+            _ => quote! { #krate::WrappingFrom::wrapping_from(#value) },
+        }
+    }
+
+    /// Assign the source value to the target field without a transform function. The value is converted with
+    /// `Into` when `into = true`, otherwise the value is copied as is. When the type of the target field is known,
+    /// the value is bound to that type so that a mismatched type is reported on the target field while the
+    /// coercions still apply e.g: `Box<u32>` into `Box<dyn Debug>`
+    ///    - { let value: u8 = __bricke_arg_age; value }
+    fn assigned(&self, value: TokenStream) -> TokenStream {
+        if self.into {
+            return quote! { ::core::convert::Into::into(#value) };
+        }
+
+        let Some(ty) = &self.ty else {
+            return value;
+        };
+
+        quote_spanned! {ty.span()=>
+            {
+                let value: #ty = #value;
+                value
+            }
+        }
+    }
+
+    /// Validate the converted value when a validate function is provided e.g:
+    ///    - { let value = arg.name; validate(&value)?; value }
+    ///
+    /// With a message, the validate function returns a bool and the message is converted into the error
+    /// when the value is invalid.
+    fn validated(&self, value: TokenStream) -> TokenStream {
+        let Some(validate) = &self.validate else {
+            return value;
+        };
+
+        let check = match &self.message {
+            Some(message) => quote! {
+                if !#validate(&value) {
+                    return Err(::core::convert::From::from(#message));
+                }
+            },
+            None => quote! { #validate(&value)?; },
+        };
+
+        quote! {
+            {
+                let value = #value;
+                #check
+                value
+            }
+        }
+    }
+}
+
+impl BrickeFieldArgs {
+    /// Create the struct template which will be used inside the field to map the path src: target
+    ///
+    /// # Arguments
+    /// * `name` - The name of the struct template.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `ty` - The type of the target field when it's known.
+    /// * `fields` - The fields of the struct template.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    pub(crate) fn create_struct_template(
+        name: Ident,
+        source_name: Ident,
+        ty: Option<Type>,
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+    ) -> TokenStream {
+        StructField {
+            ty,
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(name, source_name, fields)
+        }
+        .field_value()
+    }
+
+    /// Get the names of the source fields consumed by the target field, excluded fields don't consume any field
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target field.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `fields` - The field attributes.
+    pub(crate) fn source_fields(name: Ident, source_name: Ident, fields: Vec<Self>) -> Vec<Ident> {
+        StructField::new(name, source_name, fields).consumed()
+    }
+
+    /// Create the descriptor of the field exposed at runtime e.g:
+    ///    - ::bricke::FieldMapping { target: "name", sources: &["name"], transform: None, fallible: false, excluded: false }
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target field.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `fields` - The field attributes.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    pub(crate) fn create_descriptor_template(
+        name: Ident,
+        source_name: Ident,
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+    ) -> TokenStream {
+        let field = StructField {
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(name, source_name, fields)
+        };
+        let target = field.name.unraw().to_string();
+        let sources = field
+            .consumed()
+            .into_iter()
+            .map(|source| source.unraw().to_string());
+        let transform = match field.transform() {
+            Some(f) => {
+                let f = quote! { #f }.to_string().replace(' ', "");
+                quote! { Some(#f) }
+            }
+            None => quote! { None },
+        };
+        let fallible = field.can_fail();
+        let excluded = field.to_skip;
+
+        quote! {
+            #krate::FieldMapping {
+                target: #target,
+                sources: &[#(#sources),*],
+                transform: #transform,
+                fallible: #fallible,
+                excluded: #excluded,
+            }
+        }
+    }
+
+    /// Check whether the field provides its source fields with `rename` or `sources` rather than the source name
+    /// computed from the case convention.
+    ///
+    /// # Arguments
+    /// * `fields` - The field attributes.
+    pub(crate) fn is_renamed(fields: &[Self]) -> bool {
+        fields.iter().any(|field| {
+            matches!(
+                field,
+                BrickeFieldArgs::Rename(_) | BrickeFieldArgs::Sources(_)
+            )
+        })
+    }
+
+    /// Check whether the field borrows its source fields. Borrowing fields are built first so that the source
+    /// fields are still available when another field moves them.
+    ///
+    /// # Arguments
+    /// * `fields` - The field attributes.
+    pub(crate) fn is_borrowing(fields: &[Self]) -> bool {
+        fields
+            .iter()
+            .any(|field| matches!(field, BrickeFieldArgs::Borrow(b) if b.value()))
+    }
+
+    /// Create the async struct template. When the async transforms run concurrently, the future of the field is
+    /// returned along with the binding holding its output, the field is then built from that binding e.g:
+    ///    - name: __bricke_future_name?
+    ///
+    /// Otherwise the field is built like the struct template, awaiting the async transform inline.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the struct template.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `ty` - The type of the target field when it's known.
+    /// * `fields` - The fields of the struct template.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    /// * `concurrent` - Whether the async transforms run concurrently.
+    pub(crate) fn create_async_struct_template(
+        name: Ident,
+        source_name: Ident,
+        ty: Option<Type>,
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+        concurrent: bool,
+    ) -> (Option<(Ident, TokenStream)>, TokenStream) {
+        let field = StructField {
+            ty,
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(name, source_name, fields)
+        };
+
+        if !concurrent || !field.is_async || field.to_skip {
+            return (None, field.field_value());
+        }
+
+        let Some(future) = field.call(&field.source_value()) else {
+            return (None, field.field_value());
+        };
+
+        let name = &field.name;
+        let binding = Ident::new(
+            &format!("__bricke_future_{}", name.unraw()),
+            Span::call_site(),
+        );
+        let value = match field.is_fallible {
+            true => field.validated(field.numeric(field.time(quote! { #binding? }))),
+            false => field.validated(field.numeric(field.time(quote! { #binding }))),
+        };
+        let value = quote! { #name: #value };
+
+        (Some((binding, future)), value)
+    }
+
+    /// Create the patch template which overwrites the target field when the source field is set e.g:
+    ///    - if let Some(value) = arg.name { self.name = value; }
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target field.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `fields` - The fields of the patch template.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    pub(crate) fn create_patch_template(
+        name: Ident,
+        source_name: Ident,
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+    ) -> TokenStream {
+        let field = StructField {
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(name, source_name, fields)
+        };
+        if field.to_skip {
+            return quote! {};
+        }
+
+        let name = &field.name;
+        let from_field_name = &field.from_field_name;
+        let value = field.convert(quote! { value });
+
+        quote! {
+            if let Some(value) = arg.#from_field_name {
+                self.#name = #value;
+            }
+        }
+    }
+
+    /// Create the update template which assigns the source field onto the target field e.g:
+    ///    - self.name = arg.name;
+    ///
+    /// Excluded fields keep their current value.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the target field.
+    /// * `source_name` - The name of the source field when the field isn't renamed.
+    /// * `fields` - The fields of the update template.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    pub(crate) fn create_update_template(
+        name: Ident,
+        source_name: Ident,
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+    ) -> TokenStream {
+        let field = StructField {
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(name, source_name, fields)
+        };
+        if field.to_skip {
+            return quote! {};
+        }
+
+        let name = &field.name;
+        let from_field_name = &field.from_field_name;
+        let value = field.convert(quote! { arg.#from_field_name });
+
+        quote! {
+            self.#name = #value;
+        }
+    }
+
+    /// Create the template of a single unnamed field wrapping the whole source e.g: `Target::Created(source)`
+    ///
+    /// # Arguments
+    /// * `fields` - The fields of the unnamed field.
+    /// * `krate` - The path of the runtime crate.
+    /// * `try_conversion` - Whether the conversion can fail.
+    pub(crate) fn create_wrapper_template(
+        fields: Vec<Self>,
+        krate: &Path,
+        try_conversion: bool,
+    ) -> TokenStream {
+        let field = StructField {
+            krate: Some(krate.clone()),
+            try_conversion,
+            ..StructField::new(
+                Ident::new("arg", Span::call_site()),
+                Ident::new("arg", Span::call_site()),
+                fields,
+            )
+        };
+        let value = field.convert(quote! { arg });
+
+        quote! { 0: #value }
+    }
+}
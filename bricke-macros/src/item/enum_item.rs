@@ -0,0 +1,386 @@
+use super::ProcessItem;
+use crate::{
+    attributes::{BrickeAttributes, ConverterType},
+    case::{self, RenameRule},
+    fields::{BrickeFieldArgs, numeric_error, validate_error},
+    item::{
+        SupportedType, parse_field_attrs,
+        struct_item::{TargetField, consumed_fields},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Ident, ItemEnum, spanned::Spanned};
+
+#[derive(Debug)]
+pub enum EnumInnerFields {
+    Unnamed(Vec<Ident>),
+    Named(Vec<Ident>),
+    Unit,
+}
+
+impl EnumInnerFields {
+    /// Create the pattern used to destructure the variant e.g: `(arg_0, arg_1)` or `{ firstname, lastname }`
+    pub fn pattern(&self) -> TokenStream {
+        match self {
+            EnumInnerFields::Unnamed(idents) => quote! { (#(#idents),*) },
+            EnumInnerFields::Named(idents) => quote! { { #(#idents),* } },
+            EnumInnerFields::Unit => quote! {},
+        }
+    }
+
+    /// Create the pattern used to destructure the source variant. Named fields are renamed according to the
+    /// case convention of the source e.g: `{ firstName: first_name }`
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - The case convention of the source named fields
+    pub fn source_pattern(&self, rule: Option<RenameRule>) -> TokenStream {
+        match (self, rule) {
+            (EnumInnerFields::Named(idents), Some(rule)) => {
+                let renamed = idents.iter().map(|ident| {
+                    case::source_name(ident, Some(rule), None, None)
+                        .unwrap_or_else(|_| ident.clone())
+                });
+
+                quote! { { #(#renamed: #idents),* } }
+            }
+            _ => self.pattern(),
+        }
+    }
+
+    /// Create the compile error of a named field whose renamed source isn't a valid identifier
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - The case convention of the source named fields
+    pub fn source_pattern_error(&self, rule: Option<RenameRule>) -> Option<TokenStream> {
+        match (self, rule) {
+            (EnumInnerFields::Named(idents), Some(rule)) => idents
+                .iter()
+                .find_map(|ident| case::source_name(ident, Some(rule), None, None).err())
+                .map(|err| err.to_compile_error()),
+            _ => None,
+        }
+    }
+
+    /// Create the payload of the variant where each field is filled with its default value
+    pub fn default_payload(&self) -> TokenStream {
+        match self {
+            EnumInnerFields::Unnamed(idents) => {
+                let defaults = idents.iter().map(|_| quote! { Default::default() });
+                quote! { (#(#defaults),*) }
+            }
+            EnumInnerFields::Named(idents) => {
+                quote! { { #(#idents: Default::default()),* } }
+            }
+            EnumInnerFields::Unit => quote! {},
+        }
+    }
+}
+
+impl ProcessItem for ItemEnum {
+    fn process(
+        &self,
+        attrs: BrickeAttributes,
+        supported_type: SupportedType,
+    ) -> proc_macro2::TokenStream {
+        let target = self.ident.clone();
+
+        if let Some(error) = attrs.describe_error() {
+            return error;
+        }
+
+        if attrs.has_integer_source() {
+            return process_discriminants(self, attrs, supported_type);
+        }
+
+        if matches!(attrs.converter, ConverterType::FromStr) {
+            return process_strings(self, attrs);
+        }
+
+        if let Some(variant) = attrs.variant.clone() {
+            return process_variant(self, attrs, variant);
+        }
+
+        let mut field_tk = Vec::with_capacity(self.variants.len());
+        for item in self.variants.clone() {
+            let field_name = item.ident;
+            let parsed_enum_fields = process_enum_inner_fields(item.fields);
+
+            // Like the struct fields, we need to collect the #[bricke_field] attributes
+            let field_attrs = parse_field_attrs(&item.attrs, "Unable to parse enum attributes");
+
+            let rename_all = field_attrs.iter().find_map(|field| match field {
+                BrickeFieldArgs::RenameAll(rule) => Some(*rule),
+                _ => None,
+            });
+            if let Some(error) = attrs
+                .source_name_error([(&field_name, field_attrs.as_slice())])
+                .or_else(|| parsed_enum_fields.source_pattern_error(rename_all))
+            {
+                return error;
+            }
+
+            let tk = BrickeFieldArgs::create_enum_template(
+                field_name.clone(),
+                attrs.source.clone(),
+                attrs.source_name(&field_name),
+                field_attrs,
+                parsed_enum_fields,
+                &attrs.krate(),
+            );
+
+            // Excluded variants doesn't produce any match arm
+            if !tk.is_empty() {
+                field_tk.push(tk);
+            }
+        }
+
+        attrs.generate_conversion_template(
+            target.into(),
+            TokenStream::new(),
+            field_tk,
+            supported_type,
+        )
+    }
+}
+
+/// Process a fieldless enum converted from an integer e.g: `source = "i32"`
+///
+/// # Description
+/// Each variant is matched against its discriminant. The discriminant is either the `value` set on the variant,
+/// the explicit discriminant of the variant or the discriminant of the previous variant plus one. Unknown values
+/// return an error. The reverse conversion from the enum to the integer is generated as well.
+fn process_discriminants(
+    item: &ItemEnum,
+    attrs: BrickeAttributes,
+    supported_type: SupportedType,
+) -> TokenStream {
+    if !matches!(attrs.converter, ConverterType::TryFrom) {
+        return syn::Error::new(
+            item.ident.span(),
+            "An integer source can only be converted with the TryFrom converter",
+        )
+        .to_compile_error();
+    }
+
+    let mut field_tk = Vec::with_capacity(item.variants.len() + 1);
+    let mut values = Vec::with_capacity(item.variants.len());
+    let mut previous: Option<TokenStream> = None;
+
+    for variant in &item.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new(
+                variant.span(),
+                "Only fieldless enums can be converted from an integer",
+            )
+            .to_compile_error();
+        }
+
+        let discriminant = match (&variant.discriminant, &previous) {
+            (Some((_, expr)), _) => quote! { #expr },
+            (None, Some(prev)) => quote! { (#prev) + 1 },
+            (None, None) => quote! { 0 },
+        };
+
+        let (arm, value) = BrickeFieldArgs::create_discriminant_template(
+            variant.ident.clone(),
+            parse_field_attrs(&variant.attrs, "Unable to parse enum attributes"),
+            discriminant,
+        );
+
+        field_tk.extend(arm);
+        values.push((variant.ident.clone(), value.clone()));
+        previous = Some(value);
+    }
+
+    let unknown_error = attrs.generate_unknown_error(quote! { value });
+    field_tk.push(quote! { value => return #unknown_error });
+
+    let expanded = attrs.generate_conversion_template(
+        item.ident.clone().into(),
+        TokenStream::new(),
+        field_tk,
+        supported_type,
+    );
+    let reverse = attrs.generate_discriminant_template(item.ident.clone(), values);
+
+    quote! {
+        #expanded
+        #reverse
+    }
+}
+
+/// Process a single variant of the enum built from a struct source e.g: `variant = "Created"`
+///
+/// # Description
+/// - Named fields of the variant are mapped from the fields of the source like a struct
+/// - A single unnamed field wraps the whole source, or the output of its transform function
+/// - Unit variant ignores the source
+fn process_variant(item: &ItemEnum, attrs: BrickeAttributes, variant_name: Ident) -> TokenStream {
+    let Some(variant) = item.variants.iter().find(|v| v.ident == variant_name) else {
+        return syn::Error::new(
+            variant_name.span(),
+            format!("Variant `{}` not found in `{}`", variant_name, item.ident),
+        )
+        .to_compile_error();
+    };
+
+    if let Err(err) = attrs.require_source() {
+        return err.to_compile_error();
+    }
+
+    let (bindings, processed_fields): (TokenStream, Vec<TokenStream>) = match &variant.fields {
+        Fields::Named(named) => {
+            let mut fields: Vec<TargetField> = named
+                .named
+                .iter()
+                .map(|field| {
+                    let name = field
+                        .ident
+                        .clone()
+                        .expect("Expect to found an identifier e.g: `name`");
+
+                    (
+                        name,
+                        Some(field.ty.clone()),
+                        parse_field_attrs(&field.attrs, "Unable to parse enum attributes"),
+                    )
+                })
+                .collect();
+
+            if let Some(error) = attrs.source_name_error(
+                fields
+                    .iter()
+                    .map(|(name, _, field_attrs)| (name, field_attrs.as_slice())),
+            ) {
+                return error;
+            }
+
+            if let Some(error) = fields.iter().find_map(|(_, _, field_attrs)| {
+                validate_error(field_attrs, attrs.is_fallible())
+                    .or_else(|| numeric_error(field_attrs, attrs.is_fallible()))
+            }) {
+                return error;
+            }
+
+            let bindings = attrs.generate_bindings(consumed_fields(&fields, &attrs));
+            fields.sort_by_key(|(_, _, field_attrs)| !BrickeFieldArgs::is_borrowing(field_attrs));
+
+            let processed_fields = fields
+                .into_iter()
+                .map(|(name, ty, field_attrs)| {
+                    BrickeFieldArgs::create_struct_template(
+                        name.clone(),
+                        attrs.source_name(&name),
+                        ty,
+                        field_attrs,
+                        &attrs.krate(),
+                        attrs.is_fallible(),
+                    )
+                })
+                .collect();
+
+            (bindings, processed_fields)
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            let field_attrs =
+                parse_field_attrs(&unnamed.unnamed[0].attrs, "Unable to parse enum attributes");
+            if let Some(error) = validate_error(&field_attrs, attrs.is_fallible())
+                .or_else(|| numeric_error(&field_attrs, attrs.is_fallible()))
+            {
+                return error;
+            }
+
+            (
+                TokenStream::new(),
+                vec![BrickeFieldArgs::create_wrapper_template(
+                    field_attrs,
+                    &attrs.krate(),
+                    attrs.is_fallible(),
+                )],
+            )
+        }
+        Fields::Unnamed(unnamed) => {
+            return syn::Error::new(
+                unnamed.span(),
+                "Only a single unnamed field can wrap the source",
+            )
+            .to_compile_error();
+        }
+        Fields::Unit => (TokenStream::new(), Vec::new()),
+    };
+
+    attrs.generate_conversion_template(
+        item.ident.clone().into(),
+        bindings,
+        processed_fields,
+        SupportedType::Variant(variant_name),
+    )
+}
+
+/// Process a fieldless enum converted from and to a string e.g: `converter = "FromStr"`
+///
+/// # Description
+/// Each variant is matched against its name converted with `rename_all`, or against its `rename`, along with its
+/// aliases. Unknown strings return an error.
+fn process_strings(item: &ItemEnum, attrs: BrickeAttributes) -> TokenStream {
+    let mut field_tk = Vec::with_capacity(item.variants.len());
+    let mut values = Vec::with_capacity(item.variants.len());
+
+    for variant in &item.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new(
+                variant.span(),
+                "Only fieldless enums can be converted from a string",
+            )
+            .to_compile_error();
+        }
+
+        let (arm, repr) = BrickeFieldArgs::create_str_template(
+            variant.ident.clone(),
+            attrs.source_str(&variant.ident),
+            parse_field_attrs(&variant.attrs, "Unable to parse enum attributes"),
+            attrs.case_insensitive,
+        );
+
+        field_tk.extend(arm);
+        values.push((variant.ident.clone(), repr));
+    }
+
+    attrs.generate_str_template(item.ident.clone(), field_tk, values)
+}
+
+/// Process the enum fields e.g Enum::Variant(arg1, arg2)
+///
+/// # Description
+/// This function will take the enum fields and process them into a TokenStream
+/// - Unnamed fields will generate a list of arguments in the following format arg_0, arg_1, ...
+/// - Unit will not produce any argument
+/// - Named fields will keep the name of each field
+fn process_enum_inner_fields(fields: Fields) -> EnumInnerFields {
+    match fields {
+        Fields::Unnamed(un) => {
+            let parsed_fields: Vec<Ident> = un
+                .unnamed
+                .into_iter()
+                .enumerate()
+                .map(|(idx, field)| Ident::new(&format!("arg_{}", idx), field.span()))
+                .collect();
+
+            EnumInnerFields::Unnamed(parsed_fields)
+        }
+        // Keep the same identifiers for the named fields
+        Fields::Named(nfields) => {
+            let parsed_nfields: Vec<Ident> = nfields
+                .named
+                .into_iter()
+                .filter_map(|field| field.ident)
+                .collect();
+
+            EnumInnerFields::Named(parsed_nfields)
+        }
+        _ => EnumInnerFields::Unit,
+    }
+}
@@ -0,0 +1,163 @@
+use crate::attributes::BrickeAttributes;
+use crate::fields::BrickeFieldArgs;
+use proc_macro2::TokenStream;
+use syn::{Attribute, Fields, Item, Result, Token, punctuated::Punctuated, spanned::Spanned};
+
+pub(crate) mod enum_item;
+pub(crate) mod struct_item;
+
+/// FIELD_NAME is the field name used for bricke fields e.g: use inside of a struct
+///
+/// # Example
+/// ```
+/// use bricke::bricke;
+///
+/// struct Source {
+///     name: String,
+/// }
+///
+/// #[bricke(converter = "From", source = "Source")]
+/// struct MyStruct {
+///     #[bricke_field(rename = "name")]
+///     foo: String,
+/// }
+/// ```
+const FIELD_NAME: &str = "bricke_field";
+
+/// ITEM_NAME is the name of the attribute set on the item e.g: `#[bricke(converter = "From", source = "Source")]`.
+/// When using `#[derive(Bricke)]` it can also be set on the fields in place of `bricke_field`
+const ITEM_NAME: &str = "bricke";
+
+/// SupportedType is an enum that defines the supported types for bricke items
+///
+/// /!\ So far the lib only supports structs and enums. A struct can be converted into a single variant of an enum
+#[derive(Clone)]
+pub enum SupportedType {
+    Struct,
+    Enum,
+    Variant(syn::Ident),
+}
+
+/// ProcessItem is a trait that defines how to process a bricke item
+///
+/// Each item type (struct, enum) should implement this trait to define how to process its fields
+pub(crate) trait ProcessItem {
+    /// Process the item with the given attributes and supported type
+    ///
+    /// # Arguments
+    ///
+    /// * `attrs` - The attributes of the item
+    /// * `supported_type` - The supported type of the item (struct or enum)
+    fn process(
+        &self,
+        attrs: BrickeAttributes,
+        supported_type: SupportedType,
+    ) -> proc_macro2::TokenStream;
+}
+
+/// Generate the conversions of the item for the given attributes
+///
+/// # Arguments
+///
+/// * `item` - The struct or the enum to convert into
+/// * `attrs` - The attributes of the item
+pub(crate) fn expand(item: &Item, attrs: BrickeAttributes) -> TokenStream {
+    match item {
+        Item::Struct(item) => item.process(attrs, SupportedType::Struct),
+        Item::Enum(item) => item.process(attrs, SupportedType::Enum),
+        _ => {
+            syn::Error::new(item.span(), "Only structs and enums are supported").to_compile_error()
+        }
+    }
+}
+
+/// Parse the `#[bricke(...)]` attributes set on the item. Each attribute describes a conversion.
+///
+/// # Arguments
+///
+/// * `attrs` - The attributes of the item
+pub(crate) fn parse_item_attrs(attrs: &[Attribute]) -> Result<Vec<BrickeAttributes>> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(ITEM_NAME))
+        .map(|attr| {
+            let mut item_attrs = BrickeAttributes::default();
+            attr.parse_nested_meta(|meta| item_attrs.parse(meta))?;
+
+            Ok(item_attrs)
+        })
+        .collect()
+}
+
+/// Remove the `#[bricke(...)]` attributes of the item and return them. This is used by the attribute macro
+/// to process stacked attributes at once, as the field attributes are stripped from the item afterward.
+///
+/// # Arguments
+///
+/// * `item` - The struct or the enum
+pub(crate) fn take_item_attrs(item: &mut Item) -> Result<Vec<BrickeAttributes>> {
+    let attrs = match item {
+        Item::Struct(item) => &mut item.attrs,
+        Item::Enum(item) => &mut item.attrs,
+        _ => return Ok(Vec::new()),
+    };
+
+    let (stacked, others) = std::mem::take(attrs)
+        .into_iter()
+        .partition(|attr: &Attribute| attr.path().is_ident(ITEM_NAME));
+    *attrs = others;
+
+    parse_item_attrs(&stacked)
+}
+
+/// Remove the field attributes from the fields, the variants and the fields of the variants so that
+/// they don't get printed
+///
+/// # Arguments
+///
+/// * `item` - The struct or the enum
+pub(crate) fn strip_field_attrs(item: &mut Item) {
+    let strip_fields = |fields: &mut Fields| {
+        fields.iter_mut().for_each(|field| {
+            field.attrs.retain(|attr| !is_field_attr(attr));
+        });
+    };
+
+    match item {
+        Item::Struct(item) => strip_fields(&mut item.fields),
+        Item::Enum(item) => item.variants.iter_mut().for_each(|variant| {
+            variant.attrs.retain(|attr| !is_field_attr(attr));
+            strip_fields(&mut variant.fields);
+        }),
+        _ => {}
+    }
+}
+
+/// Check whether the attribute is a field attribute e.g: `#[bricke_field(...)]` or `#[bricke(...)]`
+fn is_field_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident(FIELD_NAME) || attr.path().is_ident(ITEM_NAME)
+}
+
+/// Collect the arguments of the `bricke_field` attributes of a field or a variant
+///
+/// # Arguments
+///
+/// * `attrs` - The attributes of the field or the variant
+/// * `error_msg` - The message used when an attribute can't be parsed
+pub(crate) fn parse_field_attrs(attrs: &[Attribute], error_msg: &str) -> Vec<BrickeFieldArgs> {
+    let mut field_attrs = Vec::with_capacity(attrs.len());
+
+    for attr in attrs {
+        if is_field_attr(attr) {
+            // Parse the #[bricke_field] attribute arguments separate by a comma and collect them
+            let meta: Punctuated<BrickeFieldArgs, Token![,]> = attr
+                .parse_args_with(Punctuated::parse_terminated)
+                .map_err(|err| syn::Error::new(attr.span(), format!("{} {}", error_msg, err)))
+                .unwrap();
+
+            field_attrs.extend(meta);
+        }
+    }
+
+    field_attrs
+}
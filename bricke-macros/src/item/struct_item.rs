@@ -0,0 +1,376 @@
+use super::{ProcessItem, parse_field_attrs};
+use crate::attributes::{BrickeAttributes, ConverterType, GenerateAttributes};
+use crate::fields::{BrickeFieldArgs, numeric_error, validate_error};
+use crate::item::SupportedType;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Arm, Ident, ItemStruct, Pat, Path, Type, spanned::Spanned};
+
+/// TargetField is the name of a target field along with its type when it's known and its `bricke_field` attributes
+pub(crate) type TargetField = (Ident, Option<Type>, Vec<BrickeFieldArgs>);
+
+impl ProcessItem for ItemStruct {
+    fn process(&self, mut attrs: BrickeAttributes, supported_type: SupportedType) -> TokenStream {
+        // The struct is built from an enum source, each arm of the match is provided by the attributes
+        if !attrs.arms.is_empty() {
+            return process_arms(self, attrs);
+        }
+
+        // The annotated struct is the source of a generated struct
+        if let Some(generate) = attrs.generate.take() {
+            return process_generate(self, attrs, generate);
+        }
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let name = field
+                    .ident
+                    .clone()
+                    .expect("Expect to found an identifier e.g: `name`");
+
+                // We parse the attributes only for the `bricke_field` attribute e.g: `#[bricke_field(transform_fn = "fn")]`
+                let field_attrs =
+                    parse_field_attrs(&field.attrs, "Unable to parse struct attribute");
+
+                (name, Some(field.ty.clone()), field_attrs)
+            })
+            .collect();
+
+        process_fields(self.ident.clone().into(), fields, &attrs, supported_type)
+    }
+}
+
+/// Process the fields of a struct according to the converter
+///
+/// # Arguments
+///
+/// * `target` - The target struct path
+/// * `fields` - The name of each target field along with its type and its `bricke_field` attributes
+/// * `attrs` - The attributes of the item
+/// * `supported_type` - The supported type of the item
+pub(crate) fn process_fields(
+    target: Path,
+    fields: Vec<TargetField>,
+    attrs: &BrickeAttributes,
+    supported_type: SupportedType,
+) -> TokenStream {
+    if let Err(err) = attrs.require_source() {
+        return err.to_compile_error();
+    }
+
+    if let Some(error) = attrs.source_name_error(
+        fields
+            .iter()
+            .map(|(name, _, field_attrs)| (name, field_attrs.as_slice())),
+    ) {
+        return error;
+    }
+
+    if let Some(error) = fields.iter().find_map(|(_, _, field_attrs)| {
+        validate_error(field_attrs, attrs.is_fallible())
+            .or_else(|| numeric_error(field_attrs, attrs.is_fallible()))
+    }) {
+        return error;
+    }
+
+    let consumed = consumed_fields(&fields, attrs);
+    let krate = attrs.krate();
+    let descriptors = fields
+        .iter()
+        .map(|(name, _, field_attrs)| {
+            BrickeFieldArgs::create_descriptor_template(
+                name.clone(),
+                attrs.source_name(name),
+                field_attrs.clone(),
+                &krate,
+                attrs.is_fallible(),
+            )
+        })
+        .collect();
+    let mapping = attrs.generate_mapping_template(&target, descriptors);
+
+    // The fields borrowing the source are built first, before other fields move the source fields
+    let mut fields = fields;
+    fields.sort_by_key(|(_, _, field_attrs)| !BrickeFieldArgs::is_borrowing(field_attrs));
+
+    let conversion = match attrs.converter {
+        ConverterType::Patch => {
+            let statements = std::iter::once(attrs.generate_strict_check(consumed))
+                .chain(fields.into_iter().map(|(name, _, field_attrs)| {
+                    BrickeFieldArgs::create_patch_template(
+                        name.clone(),
+                        attrs.source_name(&name),
+                        field_attrs,
+                        &krate,
+                        attrs.is_fallible(),
+                    )
+                }))
+                .collect();
+
+            attrs.generate_assign_template(target, statements)
+        }
+        ConverterType::Update => {
+            let statements = std::iter::once(attrs.generate_strict_check(consumed))
+                .chain(fields.into_iter().map(|(name, _, field_attrs)| {
+                    BrickeFieldArgs::create_update_template(
+                        name.clone(),
+                        attrs.source_name(&name),
+                        field_attrs,
+                        &krate,
+                        attrs.is_fallible(),
+                    )
+                }))
+                .collect();
+
+            attrs.generate_assign_template(target, statements)
+        }
+        ConverterType::Async => {
+            let (futures, processed_fields): (Vec<_>, Vec<_>) = fields
+                .into_iter()
+                .map(|(name, ty, field_attrs)| {
+                    BrickeFieldArgs::create_async_struct_template(
+                        name.clone(),
+                        attrs.source_name(&name),
+                        ty,
+                        field_attrs,
+                        &krate,
+                        attrs.is_fallible(),
+                        attrs.concurrent,
+                    )
+                })
+                .unzip();
+
+            attrs.generate_async_template(
+                target,
+                attrs.generate_bindings(consumed),
+                futures.into_iter().flatten().collect(),
+                processed_fields,
+            )
+        }
+        _ => {
+            let processed_fields = fields
+                .into_iter()
+                .map(|(name, ty, field_attrs)| {
+                    BrickeFieldArgs::create_struct_template(
+                        name.clone(),
+                        attrs.source_name(&name),
+                        ty,
+                        field_attrs,
+                        &krate,
+                        attrs.is_fallible(),
+                    )
+                })
+                .collect();
+
+            attrs.generate_conversion_template(
+                target,
+                attrs.generate_bindings(consumed),
+                processed_fields,
+                supported_type,
+            )
+        }
+    };
+
+    quote! {
+        #conversion
+        #mapping
+    }
+}
+
+/// Collect the source fields consumed by the target fields
+///
+/// # Arguments
+///
+/// * `fields` - The name of each target field along with its type and its `bricke_field` attributes
+/// * `attrs` - The attributes of the item
+pub(crate) fn consumed_fields(fields: &[TargetField], attrs: &BrickeAttributes) -> Vec<Ident> {
+    fields
+        .iter()
+        .flat_map(|(name, _, field_attrs)| {
+            BrickeFieldArgs::source_fields(
+                name.clone(),
+                attrs.source_name(name),
+                field_attrs.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Process a struct built from an enum source e.g: `arm = "Created(id, name) => Self { id, name }"`
+///
+/// # Description
+/// Each arm pattern refers to a variant of the source enum without the source prefix, the prefix is added here
+/// so that `Created(id, name)` matches `Source::Created(id, name)`. Patterns already using a path are kept as is.
+fn process_arms(item: &ItemStruct, attrs: BrickeAttributes) -> TokenStream {
+    let source = match attrs.require_source() {
+        Ok(source) => source,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let arms: Vec<TokenStream> = attrs
+        .arms
+        .iter()
+        .cloned()
+        .map(|mut arm: Arm| {
+            arm.pat = prefix_pattern(source, arm.pat);
+            let Arm {
+                pat, guard, body, ..
+            } = arm;
+            let guard = guard.map(|(if_token, cond)| quote! { #if_token #cond });
+
+            quote! { #pat #guard => #body }
+        })
+        .collect();
+
+    attrs.generate_conversion_template(
+        item.ident.clone().into(),
+        TokenStream::new(),
+        arms,
+        SupportedType::Enum,
+    )
+}
+
+/// Process a struct used as the source of a generated struct e.g: `generate = "UserDto"`
+///
+/// # Description
+/// The generated struct copies the fields of the annotated struct except the omitted ones, renamed fields use
+/// their new name. The conversion from the annotated struct into the generated struct is generated as well.
+fn process_generate(
+    item: &ItemStruct,
+    mut attrs: BrickeAttributes,
+    generate: GenerateAttributes,
+) -> TokenStream {
+    let Some(name) = generate.name else {
+        return syn::Error::new(item.ident.span(), "Expect generate to be provided")
+            .to_compile_error();
+    };
+
+    let field_names: Vec<String> = item
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+        .collect();
+
+    let unknown_field = generate
+        .omit
+        .iter()
+        .map(|omit| (omit.value(), omit.span()))
+        .chain(
+            generate
+                .rename
+                .iter()
+                .map(|(from, _)| (from.to_string(), from.span())),
+        )
+        .find(|(field, _)| !field_names.contains(field));
+
+    if let Some((field, span)) = unknown_field {
+        return syn::Error::new(span, format!("Unknown field `{}`", field)).to_compile_error();
+    }
+
+    let mut generated_fields = Vec::with_capacity(item.fields.len());
+    let mut processed_fields = Vec::with_capacity(item.fields.len());
+    let mut consumed = Vec::with_capacity(item.fields.len());
+
+    for field in &item.fields {
+        let Some(source_name) = field.ident.clone() else {
+            return syn::Error::new(field.span(), "Expect a named field").to_compile_error();
+        };
+
+        if generate.omit.iter().any(|omit| source_name == omit.value()) {
+            continue;
+        }
+
+        let target_name = generate
+            .rename
+            .iter()
+            .find(|(from, _)| *from == source_name)
+            .map(|(_, to)| to.clone())
+            .unwrap_or_else(|| source_name.clone());
+
+        let vis = &field.vis;
+        let ty = &field.ty;
+        generated_fields.push(quote! { #vis #target_name: #ty });
+
+        consumed.push(source_name.clone());
+        processed_fields.push(BrickeFieldArgs::create_struct_template(
+            target_name,
+            source_name,
+            Some(ty.clone()),
+            Vec::new(),
+            &attrs.krate(),
+            attrs.is_fallible(),
+        ));
+    }
+
+    let source = &item.ident;
+    let vis = &item.vis;
+    let derive = generate.derive;
+    let derive = (!derive.is_empty()).then(|| quote! { #[derive(#(#derive),*)] });
+    let cfg = attrs.cfg_attr();
+
+    attrs.source = Some(source.clone().into());
+    let bindings = attrs.generate_bindings(consumed);
+    let expanded = attrs.generate_conversion_template(
+        name.clone().into(),
+        bindings,
+        processed_fields,
+        SupportedType::Struct,
+    );
+
+    quote! {
+        #cfg
+        #derive
+        #vis struct #name {
+            #(#generated_fields),*
+        }
+
+        #expanded
+    }
+}
+
+/// Prefix the path of the variant pattern with the source enum
+fn prefix_pattern(source: &Path, pat: Pat) -> Pat {
+    let prefix = |path: &mut Path| {
+        if path.segments.len() == 1 {
+            let mut full_path = source.clone();
+            full_path.segments.extend(path.segments.clone());
+            *path = full_path;
+        }
+    };
+
+    match pat {
+        // A unit variant is parsed as an identifier binding e.g: `Deleted`. Lowercase identifiers are kept as
+        // bindings e.g: `other => ...`
+        Pat::Ident(pat_ident)
+            if pat_ident.subpat.is_none()
+                && pat_ident.by_ref.is_none()
+                && pat_ident.ident.to_string().starts_with(char::is_uppercase) =>
+        {
+            let ident = pat_ident.ident;
+            syn::parse_quote!(#source::#ident)
+        }
+        Pat::Path(mut p) => {
+            prefix(&mut p.path);
+            Pat::Path(p)
+        }
+        Pat::TupleStruct(mut p) => {
+            prefix(&mut p.path);
+            Pat::TupleStruct(p)
+        }
+        Pat::Struct(mut p) => {
+            prefix(&mut p.path);
+            Pat::Struct(p)
+        }
+        Pat::Or(mut p) => {
+            p.cases = p
+                .cases
+                .into_iter()
+                .map(|case| prefix_pattern(source, case))
+                .collect();
+            Pat::Or(p)
+        }
+        pat => pat,
+    }
+}
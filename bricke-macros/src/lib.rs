@@ -0,0 +1,179 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Item, parse_macro_input, spanned::Spanned};
+
+pub(crate) mod attributes;
+pub(crate) mod case;
+pub(crate) mod fields;
+pub(crate) mod item;
+pub(crate) mod mapping;
+
+use attributes::BrickeAttributes;
+use mapping::MappingSpecs;
+
+/// bricke proc macro is a macro which generates a struct or enum with the specified attributes.
+/// This allows to convert a struct to another struct which may contains similar fields while also
+/// enabled to convert a field to another type or rename that field. Below are some examples below. Although more detailed examples can be found on the [README](https://github.com/shigedangao/bricke)
+///
+/// # Examples
+///
+/// ## Map a struct to another struct
+///
+/// ```
+/// use bricke::bricke;
+///
+/// struct Foo {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// #[bricke(converter="From", source="Foo")]
+/// struct Bar {
+///     a: i32,
+///     #[bricke_field(rename="b")]
+///     c: String,
+/// }
+/// ```
+///
+/// ## Map a struct with a transform function
+///
+/// ```
+/// use bricke::bricke;
+///
+/// struct Bar {
+///     a: i32
+/// }
+///
+/// fn add_self(a: i32) -> i32 {
+///     a + a
+/// }
+///
+/// #[bricke(converter="From", source="Bar")]
+/// struct Target {
+///     #[bricke_field(transform_fn="add_self")]
+///     a: i32,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn bricke(args: TokenStream, target: TokenStream) -> TokenStream {
+    let mut input_kind = parse_macro_input!(target as Item);
+
+    let mut attrs = BrickeAttributes::default();
+    let bricke_parser = syn::meta::parser(|meta| attrs.parse(meta));
+    parse_macro_input!(args with bricke_parser);
+
+    // Stacked #[bricke(...)] attributes are processed at once as the field attributes are removed afterward
+    let stacked = match item::take_item_attrs(&mut input_kind) {
+        Ok(stacked) => stacked,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expanded: Vec<_> = std::iter::once(attrs)
+        .chain(stacked)
+        .map(|attrs| item::expand(&input_kind, attrs))
+        .collect();
+
+    item::strip_field_attrs(&mut input_kind);
+
+    quote! {
+        #input_kind
+        #(#expanded)*
+    }
+    .into()
+}
+
+/// Bricke derive macro generates the same conversions as the [`macro@bricke`] attribute macro without
+/// re-emitting the item. The conversions are described with `#[bricke(...)]` on the item, each attribute generating
+/// a conversion, and the fields are configured with either `#[bricke(...)]` or `#[bricke_field(...)]`.
+///
+/// # Example
+///
+/// ```
+/// use bricke::Bricke;
+///
+/// struct Foo {
+///     a: i32,
+///     b: String,
+/// }
+///
+/// #[derive(Bricke)]
+/// #[bricke(converter = "From", source = "Foo")]
+/// struct Bar {
+///     a: i32,
+///     #[bricke(rename = "b")]
+///     c: String,
+/// }
+/// ```
+#[proc_macro_derive(Bricke, attributes(bricke, bricke_field))]
+pub fn derive_bricke(target: TokenStream) -> TokenStream {
+    let input_kind = parse_macro_input!(target as Item);
+
+    let attrs = match &input_kind {
+        Item::Struct(item) => item::parse_item_attrs(&item.attrs),
+        Item::Enum(item) => item::parse_item_attrs(&item.attrs),
+        _ => {
+            return syn::Error::new(input_kind.span(), "Only structs and enums are supported")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let expanded: Vec<_> = match attrs {
+        Ok(attrs) => attrs
+            .into_iter()
+            .map(|attrs| item::expand(&input_kind, attrs))
+            .collect(),
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    quote! {
+        #(#expanded)*
+    }
+    .into()
+}
+
+/// bricke_map is a function-like macro which generates the conversion between two types that can't be annotated
+/// e.g: a type from a vendored crate converted into a type from another module. Each mapping lists the fields of
+/// the target type with the same `#[bricke_field(...)]` options. The conversion is described by the optional
+/// `#[bricke(...)]` attributes set on the mapping, `From` is used by default.
+///
+/// # Example
+///
+/// ```
+/// use bricke::bricke_map;
+///
+/// mod vendor {
+///     pub struct User {
+///         pub name: String,
+///         pub mail: String,
+///     }
+/// }
+///
+/// mod domain {
+///     pub struct User {
+///         pub name: String,
+///         pub email: String,
+///         pub cached: bool,
+///     }
+/// }
+///
+/// bricke_map! {
+///     #[bricke(converter = "From")]
+///     vendor::User => domain::User {
+///         name,
+///         #[bricke_field(rename = "mail")]
+///         email,
+///         #[bricke_field(exclude = true)]
+///         cached,
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn bricke_map(input: TokenStream) -> TokenStream {
+    let specs = parse_macro_input!(input as MappingSpecs);
+
+    match specs.expand() {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,137 @@
+use crate::attributes::BrickeAttributes;
+use crate::item::struct_item::process_fields;
+use crate::item::{SupportedType, parse_field_attrs, parse_item_attrs};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Ident, Path, Result, Token, Type, braced,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// MappingSpec describes a conversion between two types which can't be annotated e.g: types from another crate
+///
+/// # Example
+///
+/// ```text
+/// #[bricke(converter = "From")]
+/// vendor::User => domain::User {
+///     name,
+///     #[bricke_field(rename = "mail")]
+///     email,
+///     #[bricke_field(exclude = true)]
+///     cache,
+/// }
+/// ```
+pub struct MappingSpec {
+    attrs: Vec<Attribute>,
+    source: Path,
+    target: Path,
+    fields: Punctuated<MappedField, Token![,]>,
+}
+
+/// MappedField is a field of the target type along with its `bricke_field` attributes. The type of the field
+/// is optional, when it's written the source field is checked to be assignable to it e.g: `name` or `name: String`
+pub struct MappedField {
+    attrs: Vec<Attribute>,
+    name: Ident,
+    ty: Option<Type>,
+}
+
+/// MappingSpecs is the list of conversions declared in a single `bricke_map!` invocation
+pub struct MappingSpecs(Vec<MappingSpec>);
+
+impl Parse for MappedField {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let name: Ident = input.parse()?;
+        let ty = match input.peek(Token![:]) {
+            true => {
+                let _colon: Token![:] = input.parse()?;
+                Some(input.parse()?)
+            }
+            false => None,
+        };
+
+        Ok(MappedField { attrs, name, ty })
+    }
+}
+
+impl Parse for MappingSpec {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let source: Path = input.parse()?;
+        let _arrow: Token![=>] = input.parse()?;
+        let target: Path = input.parse()?;
+
+        let content;
+        braced!(content in input);
+        let fields = content.parse_terminated(MappedField::parse, Token![,])?;
+
+        Ok(MappingSpec {
+            attrs,
+            source,
+            target,
+            fields,
+        })
+    }
+}
+
+impl Parse for MappingSpecs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut specs = Vec::new();
+        while !input.is_empty() {
+            specs.push(input.parse()?);
+        }
+
+        Ok(MappingSpecs(specs))
+    }
+}
+
+impl MappingSpec {
+    /// Generate the conversions of the mapping. Each `#[bricke(...)]` attribute generates a conversion,
+    /// a `From` conversion is generated when there isn't any.
+    fn expand(self) -> Result<TokenStream> {
+        let mut conversions = parse_item_attrs(&self.attrs)?;
+        if conversions.is_empty() {
+            conversions.push(BrickeAttributes::default());
+        }
+
+        let expanded = conversions.into_iter().map(|mut attrs| {
+            attrs.source = Some(self.source.clone());
+
+            let fields = self
+                .fields
+                .iter()
+                .map(|field| {
+                    (
+                        field.name.clone(),
+                        field.ty.clone(),
+                        parse_field_attrs(&field.attrs, "Unable to parse mapping attribute"),
+                    )
+                })
+                .collect();
+
+            process_fields(self.target.clone(), fields, &attrs, SupportedType::Struct)
+        });
+
+        Ok(quote! {
+            #(#expanded)*
+        })
+    }
+}
+
+impl MappingSpecs {
+    /// Generate the conversions of every mapping
+    pub fn expand(self) -> Result<TokenStream> {
+        let expanded = self
+            .0
+            .into_iter()
+            .map(MappingSpec::expand)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(quote! {
+            #(#expanded)*
+        })
+    }
+}
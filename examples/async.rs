@@ -0,0 +1,109 @@
+use bricke::bricke;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct Order {
+    id: u64,
+    customer_id: u64,
+    secret: String,
+    r#type: String,
+    quantity: u64,
+}
+
+#[derive(Debug)]
+struct LookupError;
+
+// Simulates a lookup done by another service
+async fn lookup_customer(id: u64) -> Result<String, LookupError> {
+    match id {
+        0 => Err(LookupError),
+        _ => Ok(format!("customer-{}", id)),
+    }
+}
+
+// Simulates a decryption done by a local agent
+async fn decrypt(secret: String) -> String {
+    secret.chars().rev().collect()
+}
+
+// Simulates a reservation done by the inventory, the reserved quantity doesn't always fit in the target field
+async fn reserve(quantity: u64) -> u64 {
+    quantity * 100
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "Async",
+    source = "Order",
+    try_error_kind = "LookupError",
+    concurrent = true
+)]
+struct EnrichedOrder {
+    id: u64,
+    #[bricke_field(
+        rename = "customer_id",
+        transform_fn = "lookup_customer",
+        is_async = true,
+        is_fallible = true
+    )]
+    customer: String,
+    #[bricke_field(transform_fn = "decrypt", is_async = true)]
+    secret: String,
+    #[bricke_field(transform_fn = "decrypt", is_async = true)]
+    r#type: String,
+    #[bricke_field(transform_fn = "reserve", is_async = true, numeric = "saturating")]
+    quantity: u8,
+}
+
+// A minimal executor to run the example without depending on an async runtime
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn main() {
+    let order = block_on(EnrichedOrder::from_source(Order {
+        id: 1,
+        customer_id: 42,
+        secret: "terces".to_string(),
+        r#type: "sserpxe".to_string(),
+        quantity: 3,
+    }))
+    .unwrap();
+
+    assert_eq!(order.id, 1);
+    assert_eq!(order.customer, "customer-42");
+    assert_eq!(order.secret, "secret");
+    assert_eq!(order.r#type, "express");
+    assert_eq!(order.quantity, u8::MAX);
+
+    let failed = block_on(EnrichedOrder::from_source(Order {
+        id: 2,
+        customer_id: 0,
+        secret: String::new(),
+        r#type: String::new(),
+        quantity: 1,
+    }));
+    assert!(failed.is_err());
+
+    println!("{:?}", order);
+}
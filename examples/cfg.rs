@@ -0,0 +1,55 @@
+use bricke::bricke;
+
+mod store {
+    use bricke::bricke;
+
+    pub struct UserRow {
+        pub id: i64,
+        pub name: String,
+    }
+
+    // The constructor is only visible inside of the crate
+    #[derive(Debug)]
+    #[bricke(
+        converter = "From",
+        source = "UserRow",
+        method = "from_row",
+        vis = "pub(crate)"
+    )]
+    pub struct User {
+        pub id: i64,
+        pub name: String,
+    }
+}
+
+#[allow(dead_code)]
+struct DebugInfo {
+    build: String,
+}
+
+// The conversion only exists in debug builds
+#[allow(dead_code)]
+#[derive(Debug)]
+#[bricke(converter = "From", source = "DebugInfo", cfg = "debug_assertions")]
+struct Diagnostics {
+    build: String,
+}
+
+fn main() {
+    let user = store::User::from_row(store::UserRow {
+        id: 1,
+        name: "Nado".to_string(),
+    });
+    assert_eq!(user.id, 1);
+    assert_eq!(user.name, "Nado");
+    println!("{:?}", user);
+
+    #[cfg(debug_assertions)]
+    {
+        let diagnostics = Diagnostics::from(DebugInfo {
+            build: "debug".to_string(),
+        });
+        assert_eq!(diagnostics.build, "debug");
+        println!("{:?}", diagnostics);
+    }
+}
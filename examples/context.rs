@@ -0,0 +1,65 @@
+use bricke::bricke;
+use jiff::{Timestamp, Zoned, tz::TimeZone};
+
+// Runtime context needed by some of the fields
+struct Ctx {
+    timezone: TimeZone,
+    prefix: String,
+}
+
+struct Event {
+    id: u64,
+    seconds: i64,
+    title: String,
+}
+
+fn to_local_time(seconds: i64, ctx: &Ctx) -> Result<Zoned, jiff::Error> {
+    Ok(Timestamp::from_second(seconds)?.to_zoned(ctx.timezone.clone()))
+}
+
+fn prefix_title(title: String, ctx: &Ctx) -> String {
+    format!("{}{}", ctx.prefix, title)
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "FromWith",
+    source = "Event",
+    context = "Ctx",
+    try_error_kind = "jiff::Error"
+)]
+struct LocalEvent {
+    id: u64,
+    #[bricke_field(
+        rename = "seconds",
+        transform_fn = "to_local_time",
+        with_ctx = true,
+        is_fallible = true
+    )]
+    at: Zoned,
+    #[bricke_field(transform_fn = "prefix_title", with_ctx = true)]
+    title: String,
+}
+
+fn main() {
+    let ctx = Ctx {
+        timezone: TimeZone::UTC,
+        prefix: "[event] ".to_string(),
+    };
+
+    let event = LocalEvent::from_source(
+        Event {
+            id: 1,
+            seconds: 1717708136,
+            title: "launch".to_string(),
+        },
+        &ctx,
+    )
+    .unwrap();
+
+    assert_eq!(event.id, 1);
+    assert_eq!(event.at.timestamp().as_second(), 1717708136);
+    assert_eq!(event.title, "[event] launch");
+
+    println!("{:?}", event);
+}
@@ -0,0 +1,79 @@
+use bricke::bricke;
+
+// Conversion traits owned by the application
+mod convert {
+    pub trait FromProto<T>: Sized {
+        type Error;
+
+        fn from_proto(proto: T) -> Result<Self, Self::Error>;
+    }
+
+    pub trait FromRow<T> {
+        fn from_row(row: T) -> Self;
+    }
+}
+
+use convert::{FromProto, FromRow};
+
+struct ProtoUser {
+    name: String,
+    age: i32,
+}
+
+struct UserRow {
+    name: String,
+    age: u8,
+}
+
+fn check_age(age: i32) -> Result<u8, std::num::TryFromIntError> {
+    u8::try_from(age)
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "convert::FromProto",
+    method = "from_proto",
+    source = "ProtoUser",
+    try_error_kind = "std::num::TryFromIntError"
+)]
+struct User {
+    name: String,
+    #[bricke_field(transform_fn = "check_age", is_fallible = true)]
+    age: u8,
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "convert::FromRow",
+    method = "from_row",
+    source = "UserRow"
+)]
+struct Account {
+    name: String,
+    age: u8,
+}
+
+fn main() {
+    let user = User::from_proto(ProtoUser {
+        name: "Nado".to_string(),
+        age: 30,
+    })
+    .unwrap();
+    assert_eq!(user.name, "Nado");
+    assert_eq!(user.age, 30);
+
+    let invalid = User::from_proto(ProtoUser {
+        name: "Dodo".to_string(),
+        age: -1,
+    });
+    assert!(invalid.is_err());
+
+    let account = Account::from_row(UserRow {
+        name: "Nado".to_string(),
+        age: 31,
+    });
+    assert_eq!(account.name, "Nado");
+    assert_eq!(account.age, 31);
+
+    println!("{:?}", user);
+}
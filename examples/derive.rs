@@ -0,0 +1,54 @@
+use bricke::Bricke;
+
+struct Source {
+    full_name: String,
+    #[allow(dead_code)]
+    age: u8,
+}
+
+struct Legacy {
+    full_name: String,
+}
+
+fn greet(name: String) -> String {
+    format!("Hello, {}", name)
+}
+
+// The derive macro doesn't re-emit the item, each #[bricke(...)] on the item generates a conversion
+#[derive(Debug, Bricke)]
+#[bricke(converter = "From", source = "Source")]
+#[bricke(source = "Legacy")]
+struct Target {
+    #[bricke(rename = "full_name")]
+    #[bricke_field(transform_fn = "greet")]
+    name: String,
+    #[bricke(exclude = true)]
+    age: u8,
+}
+
+#[derive(Debug, PartialEq, Bricke)]
+#[bricke(converter = "FromStr", rename_all = "lowercase")]
+enum Color {
+    Red,
+    #[bricke(alias = "grey")]
+    Gray,
+}
+
+fn main() {
+    let target = Target::from(Source {
+        full_name: "Nado".to_string(),
+        age: 30,
+    });
+    assert_eq!(target.name, "Hello, Nado");
+    assert_eq!(target.age, 0);
+
+    let legacy = Target::from(Legacy {
+        full_name: "Dodo".to_string(),
+    });
+    assert_eq!(legacy.name, "Hello, Dodo");
+
+    assert_eq!("grey".parse(), Ok(Color::Gray));
+    assert_eq!(Color::Red.to_string(), "red");
+
+    println!("{:?}", target);
+}
@@ -0,0 +1,55 @@
+use bricke::{BrickeMapping, FieldMapping, bricke};
+
+#[allow(dead_code)]
+struct OrderRow {
+    id: i64,
+    total_cents: i64,
+    note: String,
+}
+
+fn to_amount(cents: i64) -> Result<u64, std::num::TryFromIntError> {
+    u64::try_from(cents)
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "OrderRow",
+    try_error_kind = "std::num::TryFromIntError",
+    describe = true
+)]
+struct Order {
+    id: i64,
+    #[bricke_field(rename = "total_cents", transform_fn = "to_amount", is_fallible = true)]
+    total: u64,
+    #[bricke_field(exclude = true)]
+    audited: bool,
+}
+
+fn main() {
+    let order = Order::try_from(OrderRow {
+        id: 1,
+        total_cents: 1_000,
+        note: "gift".to_string(),
+    })
+    .unwrap();
+    assert_eq!(order.id, 1);
+    assert_eq!(order.total, 1_000);
+    assert!(!order.audited);
+
+    assert_eq!(<Order as BrickeMapping<OrderRow>>::SOURCE, "OrderRow");
+    assert_eq!(
+        <Order as BrickeMapping<OrderRow>>::field("total"),
+        Some(&FieldMapping {
+            target: "total",
+            sources: &["total_cents"],
+            transform: Some("to_amount"),
+            fallible: true,
+            excluded: false,
+        })
+    );
+
+    for field in <Order as BrickeMapping<OrderRow>>::FIELDS {
+        println!("{:?}", field);
+    }
+}
@@ -11,6 +11,10 @@ fn process_enum_source(src_enum: SourceEnum) -> TargetEnum {
     }
 }
 
+fn default_retries() -> u8 {
+    3
+}
+
 fn process_origin_content(country: String, city: String) -> TargetEnum {
     TargetEnum::Origin {
         country: country.to_uppercase(),
@@ -32,6 +36,10 @@ enum SourceEnum {
         country: String,
         city: String,
     },
+    #[allow(dead_code)]
+    Pending(u32),
+    Empty,
+    Retry,
 }
 
 #[derive(Debug)]
@@ -56,6 +64,11 @@ enum TargetEnum {
         country: String,
         city: String,
     },
+    Pending,
+    #[bricke_field(from_unit = true)]
+    Empty(String),
+    #[bricke_field(from_unit = true, transform_fn = "default_retries")]
+    Retry(u8),
 }
 
 fn main() {
@@ -101,5 +114,14 @@ fn main() {
         assert_eq!(msg2, "World");
     }
 
+    let pending = TargetEnum::from(SourceEnum::Pending(10));
+    assert!(matches!(pending, TargetEnum::Pending));
+
+    let empty = TargetEnum::from(SourceEnum::Empty);
+    assert!(matches!(empty, TargetEnum::Empty(msg) if msg.is_empty()));
+
+    let retry = TargetEnum::from(SourceEnum::Retry);
+    assert!(matches!(retry, TargetEnum::Retry(3)));
+
     println!("{:?}", res);
 }
@@ -0,0 +1,52 @@
+use bricke::bricke;
+
+#[derive(Debug, PartialEq)]
+enum ProtoError {
+    UnknownPriority(i32),
+}
+
+fn unknown_priority(value: i32) -> ProtoError {
+    ProtoError::UnknownPriority(value)
+}
+
+// Enum as exposed by prost where each variant is represented by an i32
+#[derive(Debug, PartialEq)]
+#[bricke(converter = "TryFrom", source = "i32")]
+enum Status {
+    Unspecified,
+    Active,
+    Suspended = 10,
+    Deleted,
+}
+
+#[derive(Debug, PartialEq)]
+#[bricke(
+    converter = "TryFrom",
+    source = "i32",
+    try_error_kind = "ProtoError",
+    unknown_fn = "unknown_priority"
+)]
+enum Priority {
+    #[bricke_field(value = 1)]
+    Low,
+    #[bricke_field(value = 5)]
+    High,
+    #[bricke_field(value = -1)]
+    Unknown,
+}
+
+fn main() {
+    assert_eq!(Status::try_from(0), Ok(Status::Unspecified));
+    assert_eq!(Status::try_from(1), Ok(Status::Active));
+    assert_eq!(Status::try_from(10), Ok(Status::Suspended));
+    assert_eq!(Status::try_from(11), Ok(Status::Deleted));
+    assert_eq!(Status::try_from(3), Err(3));
+    assert_eq!(i32::from(Status::Deleted), 11);
+
+    assert_eq!(Priority::try_from(5), Ok(Priority::High));
+    assert_eq!(Priority::try_from(-1), Ok(Priority::Unknown));
+    assert_eq!(Priority::try_from(2), Err(ProtoError::UnknownPriority(2)));
+    assert_eq!(i32::from(Priority::Low), 1);
+
+    println!("{:?}", Status::try_from(10));
+}
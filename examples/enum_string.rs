@@ -0,0 +1,54 @@
+use bricke::bricke;
+
+#[derive(Debug, PartialEq)]
+#[bricke(converter = "FromStr", rename_all = "kebab-case")]
+enum Environment {
+    Development,
+    #[bricke_field(alias = "prod", alias = "live")]
+    Production,
+    #[bricke_field(rename = "qa")]
+    QualityAssurance,
+}
+
+#[derive(Debug, PartialEq)]
+enum QueryError {
+    UnknownOrder(String),
+}
+
+fn unknown_order(value: &str) -> QueryError {
+    QueryError::UnknownOrder(value.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+#[bricke(
+    converter = "FromStr",
+    rename_all = "lowercase",
+    case_insensitive = true,
+    try_error_kind = "QueryError",
+    unknown_fn = "unknown_order"
+)]
+enum SortOrder {
+    Asc,
+    Desc,
+}
+
+fn main() {
+    assert_eq!("development".parse(), Ok(Environment::Development));
+    assert_eq!("live".parse(), Ok(Environment::Production));
+    assert_eq!(
+        Environment::try_from("qa"),
+        Ok(Environment::QualityAssurance)
+    );
+    assert_eq!("staging".parse::<Environment>(), Err("staging".to_string()));
+    assert_eq!(Environment::Production.to_string(), "production");
+    assert_eq!(<&str>::from(Environment::QualityAssurance), "qa");
+
+    assert_eq!("DESC".parse(), Ok(SortOrder::Desc));
+    assert_eq!(
+        "random".parse::<SortOrder>(),
+        Err(QueryError::UnknownOrder("random".to_string()))
+    );
+    assert_eq!(SortOrder::Asc.to_string(), "asc");
+
+    println!("{}", Environment::Development);
+}
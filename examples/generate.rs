@@ -0,0 +1,37 @@
+use bricke::bricke;
+
+// The UserDto struct is generated from the User struct without the password field
+#[bricke(
+    generate = "UserDto",
+    omit = ["password"],
+    rename(email = "mail"),
+    derive(Debug, Clone, PartialEq)
+)]
+pub struct User {
+    pub id: u64,
+    pub name: String,
+    pub email: String,
+    #[allow(dead_code)]
+    password: String,
+}
+
+fn main() {
+    let user = User {
+        id: 1,
+        name: "Nado".to_string(),
+        email: "nado@dodo.la".to_string(),
+        password: "secret".to_string(),
+    };
+
+    let dto = UserDto::from(user);
+    assert_eq!(
+        dto,
+        UserDto {
+            id: 1,
+            name: "Nado".to_string(),
+            mail: "nado@dodo.la".to_string(),
+        }
+    );
+
+    println!("{:?}", dto);
+}
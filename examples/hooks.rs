@@ -0,0 +1,72 @@
+use bricke::bricke;
+
+#[derive(Clone)]
+struct ArticleRow {
+    title: String,
+    body: String,
+}
+
+fn normalize(mut row: ArticleRow) -> ArticleRow {
+    row.title = row.title.trim().to_string();
+    row
+}
+
+fn reject_empty(row: ArticleRow) -> Result<ArticleRow, String> {
+    match row.body.is_empty() {
+        true => Err("empty body".to_string()),
+        false => Ok(row),
+    }
+}
+
+fn count_words(article: &mut Article) {
+    article.words = article.body.split_whitespace().count();
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "From",
+    source = "ArticleRow",
+    before = "normalize",
+    after = "count_words"
+)]
+struct Article {
+    title: String,
+    body: String,
+    #[bricke_field(exclude = true)]
+    words: usize,
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "ArticleRow",
+    try_error_kind = "String",
+    before = "reject_empty"
+)]
+struct Draft {
+    title: String,
+    body: String,
+}
+
+fn main() {
+    let row = ArticleRow {
+        title: "  Hello  ".to_string(),
+        body: "Hello world".to_string(),
+    };
+
+    let article = Article::from(row.clone());
+    assert_eq!(article.title, "Hello");
+    assert_eq!(article.words, 2);
+
+    let draft = Draft::try_from(row).unwrap();
+    assert_eq!(draft.title, "  Hello  ");
+    assert_eq!(draft.body, "Hello world");
+
+    let empty = ArticleRow {
+        title: "Empty".to_string(),
+        body: String::new(),
+    };
+    assert_eq!(Draft::try_from(empty).unwrap_err(), "empty body");
+
+    println!("{:?}", article);
+}
@@ -0,0 +1,37 @@
+use bricke::bricke;
+
+struct MeasureRow {
+    id: u32,
+    celsius: f32,
+    label: &'static str,
+    tags: &'static [&'static str; 2],
+}
+
+// A direct copy still applies the coercions e.g: `tags` is coerced from an array into a slice
+#[derive(Debug)]
+#[bricke(converter = "From", source = "MeasureRow")]
+struct Measure {
+    #[bricke_field(into = true)]
+    id: u64,
+    #[bricke_field(into = true)]
+    celsius: f64,
+    #[bricke_field(into = true)]
+    label: String,
+    tags: &'static [&'static str],
+}
+
+fn main() {
+    let measure = Measure::from(MeasureRow {
+        id: 7,
+        celsius: 21.5,
+        label: "kitchen",
+        tags: &["indoor", "ground"],
+    });
+
+    assert_eq!(measure.id, 7);
+    assert_eq!(measure.celsius, 21.5);
+    assert_eq!(measure.label, "kitchen");
+    assert_eq!(measure.tags, ["indoor", "ground"]);
+
+    println!("{:?}", measure);
+}
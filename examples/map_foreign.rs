@@ -0,0 +1,51 @@
+use bricke::bricke_map;
+
+// A dummy module representing a vendored crate that we can't annotate
+mod vendor {
+    pub struct Account {
+        pub id: u64,
+        pub display_name: String,
+        pub mail: String,
+    }
+}
+
+mod domain {
+    #[derive(Debug)]
+    pub struct Account {
+        pub id: u64,
+        pub name: String,
+        pub email: String,
+        pub cached: bool,
+    }
+}
+
+fn normalize_email(email: String) -> String {
+    email.to_lowercase()
+}
+
+bricke_map! {
+    vendor::Account => domain::Account {
+        id: u64,
+        #[bricke_field(rename = "display_name")]
+        name: String,
+        #[bricke_field(rename = "mail", transform_fn = "normalize_email")]
+        email: String,
+        #[bricke_field(exclude = true)]
+        cached: bool,
+    }
+}
+
+fn main() {
+    let account = domain::Account::from(vendor::Account {
+        id: 1,
+        display_name: "Nado".to_string(),
+        mail: "NADO@DODO.LA".to_string(),
+    });
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "Nado");
+    assert_eq!(account.email, "nado@dodo.la");
+    assert!(!account.cached);
+
+    println!("{:?}", account);
+}
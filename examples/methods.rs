@@ -0,0 +1,59 @@
+use bricke::bricke;
+
+struct UserRow {
+    id: i64,
+    name: String,
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "From",
+    source = "UserRow",
+    method = "from_db",
+    into_method = "into_dto"
+)]
+struct UserDto {
+    id: i64,
+    name: String,
+}
+
+struct RawAmount {
+    cents: i64,
+}
+
+fn to_unsigned(cents: i64) -> Result<u64, std::num::TryFromIntError> {
+    u64::try_from(cents)
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "RawAmount",
+    try_error_kind = "std::num::TryFromIntError",
+    into_method = "into_amount"
+)]
+struct Amount {
+    #[bricke_field(transform_fn = "to_unsigned", is_fallible = true)]
+    cents: u64,
+}
+
+fn main() {
+    let dto = UserDto::from_db(UserRow {
+        id: 1,
+        name: "Nado".to_string(),
+    });
+    assert_eq!(dto.id, 1);
+
+    let dto = UserRow {
+        id: 2,
+        name: "Dodo".to_string(),
+    }
+    .into_dto();
+    assert_eq!(dto.name, "Dodo");
+
+    let amount = RawAmount { cents: 100 }.into_amount().unwrap();
+    assert_eq!(amount.cents, 100);
+    assert!(RawAmount { cents: -1 }.into_amount().is_err());
+
+    println!("{:?}", dto);
+}
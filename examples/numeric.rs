@@ -0,0 +1,80 @@
+use bricke::{BrickeError, BrickeErrorKind, bricke};
+
+#[derive(Clone)]
+struct UserRow {
+    id: i64,
+    age: i32,
+    score: i64,
+    checksum: u64,
+}
+
+// `id` and `age` return an error when the value doesn't fit in the target type
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "UserRow",
+    try_error_kind = "bricke::BrickeError"
+)]
+struct User {
+    #[bricke_field(numeric = "checked")]
+    id: u32,
+    #[bricke_field(numeric = "checked")]
+    age: u8,
+    #[bricke_field(numeric = "saturating")]
+    score: u16,
+    #[bricke_field(numeric = "wrapping")]
+    checksum: u32,
+}
+
+// Only the conversions which can't fail are available with `From`
+#[derive(Debug)]
+#[bricke(converter = "From", source = "UserRow")]
+struct UserSummary {
+    #[bricke_field(numeric = "saturating")]
+    id: u32,
+    #[bricke_field(numeric = "saturating")]
+    age: u8,
+    #[bricke_field(numeric = "saturating")]
+    score: i8,
+    #[bricke_field(numeric = "wrapping")]
+    checksum: u8,
+}
+
+fn main() -> Result<(), BrickeError> {
+    let row = UserRow {
+        id: 42,
+        age: 30,
+        score: -5,
+        checksum: u64::from(u32::MAX) + 2,
+    };
+
+    let user = User::try_from(row.clone())?;
+    assert_eq!(user.id, 42);
+    assert_eq!(user.age, 30);
+    assert_eq!(user.score, 0);
+    assert_eq!(user.checksum, 1);
+
+    let summary = UserSummary::from(UserRow {
+        id: -1,
+        age: 300,
+        ..row.clone()
+    });
+    assert_eq!(summary.id, 0);
+    assert_eq!(summary.age, u8::MAX);
+    assert_eq!(summary.score, -5);
+    assert_eq!(summary.checksum, 1);
+
+    let err = User::try_from(UserRow {
+        id: i64::MAX,
+        ..row
+    })
+    .unwrap_err();
+    assert_eq!(err.path(), "id");
+    assert_eq!(err.kind(), &BrickeErrorKind::OutOfRange);
+
+    println!("{:?}", user);
+    println!("{:?}", summary);
+    println!("{}", err);
+
+    Ok(())
+}
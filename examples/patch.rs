@@ -0,0 +1,62 @@
+use bricke::bricke;
+
+// PATCH request where every field is optional
+struct UserPatch {
+    name: Option<String>,
+    mail: Option<String>,
+    age: Option<u32>,
+}
+
+fn normalize_email(email: String) -> String {
+    email.to_lowercase()
+}
+
+fn check_age(age: u32) -> Result<u8, std::io::Error> {
+    u8::try_from(age).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "Patch",
+    source = "UserPatch",
+    try_error_kind = "std::io::Error"
+)]
+struct User {
+    name: String,
+    #[bricke_field(rename = "mail", transform_fn = "normalize_email")]
+    email: String,
+    #[bricke_field(transform_fn = "check_age", is_fallible = true)]
+    age: u8,
+    #[bricke_field(exclude = true)]
+    version: u32,
+}
+
+fn main() {
+    let mut user = User {
+        name: "Nado".to_string(),
+        email: "nado@dodo.la".to_string(),
+        age: 30,
+        version: 1,
+    };
+
+    user.apply(UserPatch {
+        name: None,
+        mail: Some("NADO@LAO.LA".to_string()),
+        age: Some(31),
+    })
+    .unwrap();
+
+    assert_eq!(user.name, "Nado");
+    assert_eq!(user.email, "nado@lao.la");
+    assert_eq!(user.age, 31);
+    assert_eq!(user.version, 1);
+
+    let res = user.apply(UserPatch {
+        name: None,
+        mail: None,
+        age: Some(1000),
+    });
+    assert!(res.is_err());
+
+    println!("{:?}", user);
+}
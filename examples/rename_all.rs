@@ -0,0 +1,73 @@
+use bricke::bricke;
+
+// Fields generated by a code generator using the camelCase convention
+#[allow(non_snake_case)]
+struct GeneratedUser {
+    firstName: String,
+    lastName: String,
+    emailAddress: String,
+}
+
+#[derive(Debug)]
+#[bricke(converter = "From", source = "GeneratedUser", rename_all = "camelCase")]
+struct User {
+    first_name: String,
+    last_name: String,
+    #[bricke_field(rename = "emailAddress")]
+    email: String,
+}
+
+// Variants coming from C bindings
+#[allow(non_camel_case_types, non_snake_case, clippy::upper_case_acronyms)]
+enum RawStatus {
+    PB_NOT_STARTED,
+    PB_IN_PROGRESS {
+        #[allow(dead_code)]
+        doneCount: u32,
+    },
+    PB_FAILED {
+        errorCode: i32,
+    },
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "From",
+    source = "RawStatus",
+    rename_all = "SCREAMING_SNAKE_CASE",
+    strip_prefix = "PB_"
+)]
+enum Status {
+    NotStarted,
+    InProgress,
+    #[bricke_field(rename_all = "camelCase")]
+    Failed {
+        error_code: i32,
+    },
+}
+
+fn main() {
+    let user = User::from(GeneratedUser {
+        firstName: "Nado".to_string(),
+        lastName: "Dodo".to_string(),
+        emailAddress: "nado@dodo.la".to_string(),
+    });
+    assert_eq!(user.first_name, "Nado");
+    assert_eq!(user.last_name, "Dodo");
+    assert_eq!(user.email, "nado@dodo.la");
+
+    assert!(matches!(
+        Status::from(RawStatus::PB_NOT_STARTED),
+        Status::NotStarted
+    ));
+    assert!(matches!(
+        Status::from(RawStatus::PB_IN_PROGRESS { doneCount: 2 }),
+        Status::InProgress
+    ));
+    assert!(matches!(
+        Status::from(RawStatus::PB_FAILED { errorCode: -1 }),
+        Status::Failed { error_code: -1 }
+    ));
+
+    println!("{:?}", user);
+}
@@ -0,0 +1,93 @@
+use bricke::{BrickeError, BrickeErrorKind, BrickeMerge, bricke};
+
+// The runtime crate can be re-exported by another crate, the generated code then refers to the re-export
+mod deps {
+    pub use bricke;
+}
+
+struct AddressForm {
+    street: Option<String>,
+    zip_code: Option<String>,
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "AddressForm",
+    try_error_kind = "bricke::BrickeError"
+)]
+struct Address {
+    #[bricke_field(required = true)]
+    street: String,
+    #[bricke_field(required = true)]
+    zip_code: String,
+}
+
+struct AddressPatch {
+    street: Option<String>,
+    zip_code: Option<String>,
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "Patch",
+    source = "AddressPatch",
+    crate = "crate::deps::bricke"
+)]
+struct Contact {
+    street: String,
+    zip_code: String,
+}
+
+/// Apply any patch through the shared trait
+fn apply_all<T, P>(target: &mut T, patches: Vec<P>) -> Result<(), T::Error>
+where
+    T: BrickeMerge<P>,
+{
+    patches
+        .into_iter()
+        .try_for_each(|patch| target.merge(patch))
+}
+
+fn main() {
+    let address = Address::try_from(AddressForm {
+        street: Some("1 rue de la Paix".to_string()),
+        zip_code: Some("75002".to_string()),
+    })
+    .unwrap();
+    assert_eq!(address.street, "1 rue de la Paix");
+
+    let err: BrickeError = Address::try_from(AddressForm {
+        street: Some("1 rue de la Paix".to_string()),
+        zip_code: None,
+    })
+    .unwrap_err();
+    assert_eq!(err.kind(), &BrickeErrorKind::Missing);
+    assert_eq!(
+        err.at("address").to_string(),
+        "address.zip_code: missing value"
+    );
+
+    let mut contact = Contact {
+        street: address.street,
+        zip_code: address.zip_code,
+    };
+    apply_all(
+        &mut contact,
+        vec![
+            AddressPatch {
+                street: Some("2 rue de Rivoli".to_string()),
+                zip_code: None,
+            },
+            AddressPatch {
+                street: None,
+                zip_code: Some("75001".to_string()),
+            },
+        ],
+    )
+    .unwrap();
+    assert_eq!(contact.street, "2 rue de Rivoli");
+    assert_eq!(contact.zip_code, "75001");
+
+    println!("{:?}", contact);
+}
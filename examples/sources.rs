@@ -0,0 +1,48 @@
+use bricke::bricke;
+
+struct PersonRow {
+    firstname: String,
+    lastname: String,
+    tags: Vec<String>,
+}
+
+fn full_name(firstname: &str, lastname: &str) -> String {
+    format!("{} {}", firstname, lastname)
+}
+
+fn count(tags: &[String]) -> usize {
+    tags.len()
+}
+
+// `display_name` borrows the firstname and the lastname which are moved afterward into their own fields
+#[derive(Debug)]
+#[bricke(converter = "From", source = "PersonRow")]
+struct Person {
+    firstname: String,
+    lastname: String,
+    #[bricke_field(
+        transform_fn = "full_name",
+        sources = ["firstname", "lastname"],
+        borrow = true
+    )]
+    display_name: String,
+    #[bricke_field(transform_fn = "count", sources = ["tags"], borrow = true)]
+    tag_count: usize,
+    tags: Vec<String>,
+}
+
+fn main() {
+    let person = Person::from(PersonRow {
+        firstname: "Nado".to_string(),
+        lastname: "Dodo".to_string(),
+        tags: vec!["admin".to_string()],
+    });
+
+    assert_eq!(person.firstname, "Nado");
+    assert_eq!(person.lastname, "Dodo");
+    assert_eq!(person.display_name, "Nado Dodo");
+    assert_eq!(person.tag_count, 1);
+    assert_eq!(person.tags, ["admin"]);
+
+    println!("{:?}", person);
+}
@@ -0,0 +1,39 @@
+use bricke::bricke;
+
+struct UserRow {
+    id: i64,
+    email: String,
+    password_hash: String,
+    legacy_id: Option<i32>,
+}
+
+// Adding a field to `UserRow` fails to compile until the field is mapped or listed in `ignore_source`
+#[derive(Debug)]
+#[bricke(
+    converter = "From",
+    source = "UserRow",
+    strict = true,
+    ignore_source = ["password_hash", "legacy_id"]
+)]
+struct User {
+    id: i64,
+    #[bricke_field(rename = "email")]
+    mail: String,
+}
+
+fn main() {
+    let row = UserRow {
+        id: 1,
+        email: "nado@example.com".to_string(),
+        password_hash: "hash".to_string(),
+        legacy_id: None,
+    };
+    assert_eq!(row.password_hash, "hash");
+    assert!(row.legacy_id.is_none());
+
+    let user = User::from(row);
+    assert_eq!(user.id, 1);
+    assert_eq!(user.mail, "nado@example.com");
+
+    println!("{:?}", user);
+}
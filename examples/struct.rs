@@ -50,6 +50,7 @@ struct Target {
     hello: String,
 }
 
+#[allow(clippy::disallowed_names)]
 fn main() {
     let b = Source {
         name: "Doudou".to_string(),
@@ -22,6 +22,7 @@ struct Target {
     hello: String,
 }
 
+#[allow(clippy::disallowed_names)]
 fn main() {
     let b = Source {
         hello: "chaichai".to_string(),
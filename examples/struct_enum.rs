@@ -0,0 +1,60 @@
+use bricke::bricke;
+
+struct CreateUser {
+    name: String,
+    email: String,
+}
+
+#[derive(Debug)]
+struct DeleteUser {
+    id: u64,
+}
+
+fn normalize_email(email: String) -> String {
+    email.to_lowercase()
+}
+
+// Each command is turned into a variant of the event
+#[derive(Debug)]
+#[bricke(converter = "From", source = "CreateUser", variant = "Created")]
+#[bricke(converter = "From", source = "DeleteUser", variant = "Deleted")]
+enum UserEvent {
+    Created {
+        name: String,
+        #[bricke_field(transform_fn = "normalize_email")]
+        email: String,
+    },
+    Deleted(DeleteUser),
+}
+
+// Projection of the events into a struct
+#[derive(Debug)]
+#[bricke(
+    converter = "From",
+    source = "UserEvent",
+    arm = "Created { name, email } => Self { name, email, active: true }",
+    arm = "Deleted(_) => Self { name: String::new(), email: String::new(), active: false }"
+)]
+struct UserProjection {
+    name: String,
+    email: String,
+    active: bool,
+}
+
+fn main() {
+    let created = UserEvent::from(CreateUser {
+        name: "Nado".to_string(),
+        email: "NADO@DODO.LA".to_string(),
+    });
+
+    let projection = UserProjection::from(created);
+    assert_eq!(projection.name, "Nado");
+    assert_eq!(projection.email, "nado@dodo.la");
+    assert!(projection.active);
+
+    let deleted = UserEvent::from(DeleteUser { id: 1 });
+    assert!(matches!(deleted, UserEvent::Deleted(DeleteUser { id: 1 })));
+    assert!(!UserProjection::from(deleted).active);
+
+    println!("{:?}", projection);
+}
@@ -0,0 +1,122 @@
+use bricke::{BrickeError, BrickeErrorKind, BrickeMapping, bricke};
+use jiff::{SignedDuration, Timestamp, Zoned, tz::TimeZone};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Clone)]
+struct EventRow {
+    id: i64,
+    created_at: i64,
+    updated_at: i64,
+    scheduled_at: String,
+}
+
+// Epoch values and strings are parsed into timestamps, an invalid value returns an error
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "EventRow",
+    try_error_kind = "bricke::BrickeError",
+    describe = true
+)]
+struct Event {
+    id: i64,
+    #[bricke_field(time = "epoch_seconds")]
+    created_at: Timestamp,
+    #[bricke_field(time = "epoch_millis")]
+    updated_at: Zoned,
+    #[bricke_field(time = "rfc3339")]
+    scheduled_at: Zoned,
+}
+
+// Timestamps are always converted into their representation
+#[derive(Debug)]
+#[bricke(converter = "From", source = "Event")]
+struct EventPayload {
+    id: i64,
+    #[bricke_field(time = "rfc3339")]
+    created_at: String,
+    #[bricke_field(time = "epoch_millis")]
+    updated_at: i64,
+    #[bricke_field(time = "rfc3339")]
+    scheduled_at: String,
+}
+
+// Simulates a lookup of the reminder time done by another service
+async fn reminder(created_at: Timestamp) -> Timestamp {
+    created_at + SignedDuration::from_hours(1)
+}
+
+// The timestamp returned by the async transform is converted into its representation
+#[derive(Debug)]
+#[bricke(converter = "Async", source = "Event", concurrent = true)]
+struct EventReminder {
+    id: i64,
+    #[bricke_field(
+        rename = "created_at",
+        transform_fn = "reminder",
+        is_async = true,
+        time = "rfc3339"
+    )]
+    remind_at: String,
+}
+
+// The transform is ready right away, a single poll is enough to run the example without an async runtime
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut cx = Context::from_waker(Waker::noop());
+    match pin!(future).poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("Expect the future to be ready"),
+    }
+}
+
+fn main() -> Result<(), BrickeError> {
+    let row = EventRow {
+        id: 1,
+        created_at: 1_718_810_565,
+        updated_at: 1_718_810_565_500,
+        scheduled_at: "2024-06-20T09:00:00+02:00".to_string(),
+    };
+
+    // The parsed fields are described as fallible
+    let fields = <Event as BrickeMapping<EventRow>>::FIELDS;
+    let fallible: Vec<_> = fields.iter().map(|field| field.fallible).collect();
+    assert_eq!(fallible, [false, true, true, true]);
+
+    let event = Event::try_from(row.clone())?;
+    assert_eq!(
+        event.created_at,
+        Timestamp::from_second(1_718_810_565).unwrap()
+    );
+    assert_eq!(event.updated_at.time_zone(), &TimeZone::UTC);
+    assert_eq!(
+        event.updated_at.timestamp().as_millisecond(),
+        1_718_810_565_500
+    );
+    assert_eq!(event.scheduled_at.offset().seconds(), 7200);
+
+    let reminder = block_on(EventReminder::from_source(Event::try_from(row.clone())?));
+    assert_eq!(reminder.id, 1);
+    assert_eq!(reminder.remind_at, "2024-06-19T16:22:45Z");
+
+    let payload = EventPayload::from(event);
+    assert_eq!(payload.id, 1);
+    assert_eq!(payload.created_at, "2024-06-19T15:22:45Z");
+    assert_eq!(payload.updated_at, 1_718_810_565_500);
+    assert_eq!(payload.scheduled_at, "2024-06-20T09:00:00+02:00");
+
+    let err = Event::try_from(EventRow {
+        scheduled_at: "tomorrow".to_string(),
+        ..row
+    })
+    .unwrap_err();
+    assert_eq!(err.path(), "scheduled_at");
+    assert!(matches!(err.kind(), BrickeErrorKind::Invalid(_)));
+
+    println!("{:?}", reminder);
+    println!("{:?}", payload);
+    println!("{}", err);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::time::{Duration, SystemTime};
+
+use bricke::bricke;
+
+struct AccountRow {
+    username: String,
+    email: String,
+    nickname: Option<String>,
+    roles: Vec<String>,
+    timeout_ms: u64,
+    created_at: u64,
+}
+
+// Built-in transforms are referenced by their keyword or by their path
+#[derive(Debug)]
+#[bricke(converter = "From", source = "AccountRow")]
+struct Account {
+    #[bricke_field(transform = trim)]
+    username: String,
+    #[bricke_field(transform = "bricke::lowercase")]
+    email: String,
+    #[bricke_field(transform = empty_to_none)]
+    nickname: Option<String>,
+    #[bricke_field(transform = dedup)]
+    roles: Vec<String>,
+    #[bricke_field(rename = "timeout_ms", transform = millis_to_duration)]
+    timeout: Duration,
+    #[bricke_field(transform = secs_to_system_time)]
+    created_at: SystemTime,
+}
+
+enum SourceEvent {
+    Login(String),
+    Logout(String),
+}
+
+#[derive(Debug)]
+#[bricke(converter = "From", source = "SourceEvent")]
+enum Event {
+    #[bricke_field(transform = trim)]
+    Login(String),
+    #[bricke_field(transform = uppercase)]
+    Logout(String),
+}
+
+fn main() {
+    let account = Account::from(AccountRow {
+        username: "  nado ".to_string(),
+        email: "Nado@Example.COM".to_string(),
+        nickname: Some(String::new()),
+        roles: vec!["admin".to_string(), "user".to_string(), "admin".to_string()],
+        timeout_ms: 1500,
+        created_at: 60,
+    });
+
+    assert_eq!(account.username, "nado");
+    assert_eq!(account.email, "nado@example.com");
+    assert_eq!(account.nickname, None);
+    assert_eq!(account.roles, ["admin", "user"]);
+    assert_eq!(account.timeout, Duration::from_millis(1500));
+    assert_eq!(
+        account.created_at,
+        SystemTime::UNIX_EPOCH + Duration::from_secs(60)
+    );
+
+    let login = Event::from(SourceEvent::Login(" nado ".to_string()));
+    assert!(matches!(&login, Event::Login(name) if name == "nado"));
+
+    let logout = Event::from(SourceEvent::Logout("nado".to_string()));
+    assert!(matches!(&logout, Event::Logout(name) if name == "NADO"));
+
+    println!("{:?}", account);
+    println!("{:?} {:?}", login, logout);
+}
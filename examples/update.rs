@@ -0,0 +1,41 @@
+use bricke::bricke;
+
+struct Profile {
+    name: String,
+    followers: u32,
+}
+
+fn format_followers(followers: u32) -> String {
+    format!("{} followers", followers)
+}
+
+// The cached profile is built from the source and refreshed later on without losing the local fields
+#[derive(Debug)]
+#[bricke(converter = "From", source = "Profile")]
+#[bricke(converter = "Update", source = "Profile")]
+struct CachedProfile {
+    name: String,
+    #[bricke_field(transform_fn = "format_followers")]
+    followers: String,
+    #[bricke_field(exclude = true)]
+    hits: u32,
+}
+
+fn main() {
+    let mut cached = CachedProfile::from(Profile {
+        name: "Nado".to_string(),
+        followers: 10,
+    });
+    cached.hits = 5;
+
+    cached.update_from(Profile {
+        name: "Dodo".to_string(),
+        followers: 42,
+    });
+
+    assert_eq!(cached.name, "Dodo");
+    assert_eq!(cached.followers, "42 followers");
+    assert_eq!(cached.hits, 5);
+
+    println!("{:?}", cached);
+}
@@ -0,0 +1,77 @@
+use bricke::bricke;
+
+struct SignupForm {
+    name: String,
+    age: i32,
+    password: String,
+    password_confirmation: String,
+}
+
+fn to_age(age: i32) -> Result<u8, String> {
+    u8::try_from(age).map_err(|err| err.to_string())
+}
+
+fn is_adult(age: &u8) -> bool {
+    *age >= 18
+}
+
+fn check_name(name: &str) -> Result<(), String> {
+    match name.len() {
+        1..=32 => Ok(()),
+        _ => Err(format!("invalid name length: {}", name.len())),
+    }
+}
+
+fn check_passwords(signup: &Signup) -> Result<(), String> {
+    match signup.password == signup.password_confirmation {
+        true => Ok(()),
+        false => Err("passwords don't match".to_string()),
+    }
+}
+
+#[derive(Debug)]
+#[bricke(
+    converter = "TryFrom",
+    source = "SignupForm",
+    try_error_kind = "String",
+    validate = "check_passwords"
+)]
+struct Signup {
+    #[bricke_field(validate = "check_name")]
+    name: String,
+    #[bricke_field(
+        transform_fn = "to_age",
+        is_fallible = true,
+        validate = "is_adult",
+        message = "must be an adult"
+    )]
+    age: u8,
+    password: String,
+    password_confirmation: String,
+}
+
+fn form(name: &str, age: i32, confirmation: &str) -> SignupForm {
+    SignupForm {
+        name: name.to_string(),
+        age,
+        password: "secret".to_string(),
+        password_confirmation: confirmation.to_string(),
+    }
+}
+
+fn main() {
+    let signup = Signup::try_from(form("Nado", 30, "secret")).unwrap();
+    assert_eq!(signup.name, "Nado");
+    assert_eq!(signup.age, 30);
+
+    let err = Signup::try_from(form("", 30, "secret")).unwrap_err();
+    assert_eq!(err, "invalid name length: 0");
+
+    let err = Signup::try_from(form("Nado", 12, "secret")).unwrap_err();
+    assert_eq!(err, "must be an adult");
+
+    let err = Signup::try_from(form("Nado", 30, "other")).unwrap_err();
+    assert_eq!(err, "passwords don't match");
+
+    println!("{:?}", signup);
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// BrickeErrorKind is the reason why a field couldn't be converted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrickeErrorKind {
+    /// The source field doesn't have any value
+    Missing,
+    /// The source value doesn't fit in the target type
+    OutOfRange,
+    /// The source value is rejected along with a message
+    Invalid(String),
+}
+
+/// BrickeError is the error of a field conversion along with the path of the field e.g: `address.zip_code`
+///
+/// # Example
+///
+/// ```
+/// use bricke::{BrickeError, BrickeErrorKind};
+///
+/// let err = BrickeError::missing("zip_code").at("address");
+/// assert_eq!(err.path(), "address.zip_code");
+/// assert_eq!(err.kind(), &BrickeErrorKind::Missing);
+/// assert_eq!(err.to_string(), "address.zip_code: missing value");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrickeError {
+    path: Vec<String>,
+    kind: BrickeErrorKind,
+}
+
+impl BrickeError {
+    /// Create the error of a field
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The name of the field
+    /// * `kind` - The reason of the error
+    pub fn new(field: impl Into<String>, kind: BrickeErrorKind) -> Self {
+        BrickeError {
+            path: vec![field.into()],
+            kind,
+        }
+    }
+
+    /// Create the error of a field without any value
+    pub fn missing(field: impl Into<String>) -> Self {
+        Self::new(field, BrickeErrorKind::Missing)
+    }
+
+    /// Create the error of a field whose value doesn't fit in the target type
+    pub fn out_of_range(field: impl Into<String>) -> Self {
+        Self::new(field, BrickeErrorKind::OutOfRange)
+    }
+
+    /// Create the error of a field whose value is rejected
+    pub fn invalid(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(field, BrickeErrorKind::Invalid(message.into()))
+    }
+
+    /// Prefix the path of the field with its parent when the error comes from a nested conversion
+    pub fn at(mut self, parent: impl Into<String>) -> Self {
+        self.path.insert(0, parent.into());
+        self
+    }
+
+    /// Get the path of the field e.g: `address.zip_code`
+    pub fn path(&self) -> String {
+        self.path.join(".")
+    }
+
+    /// Get the reason of the error
+    pub fn kind(&self) -> &BrickeErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for BrickeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrickeErrorKind::Missing => f.write_str("missing value"),
+            BrickeErrorKind::OutOfRange => f.write_str("value out of range"),
+            BrickeErrorKind::Invalid(message) => write!(f, "invalid value: {}", message),
+        }
+    }
+}
+
+impl fmt::Display for BrickeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path(), self.kind)
+    }
+}
+
+impl std::error::Error for BrickeError {}
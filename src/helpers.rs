@@ -0,0 +1,14 @@
+use crate::BrickeError;
+
+/// Get the value of a source field which must be set, a missing value returns an error with the field path.
+/// It's used by the fields set with `required = true`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(bricke::required(Some(1), "id"), Ok(1));
+/// assert_eq!(bricke::required::<i32>(None, "id"), Err(bricke::BrickeError::missing("id")));
+/// ```
+pub fn required<T>(value: Option<T>, field: &'static str) -> Result<T, BrickeError> {
+    value.ok_or_else(|| BrickeError::missing(field))
+}
@@ -1,73 +1,41 @@
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::{Item, parse_macro_input};
+//! bricke maps a type to another. The conversions are generated by the [`macro@bricke`] attribute macro, the
+//! [`Bricke`] derive macro and the [`bricke_map!`] macro. This crate re-exports the macros along with the items
+//! used by the generated code: the conversion traits, the [`BrickeError`] and the helpers. The generated code
+//! refers to this crate as `::bricke`, the path can be overridden with `#[bricke(crate = "...")]`. More detailed examples can be found on the [README](https://github.com/shigedangao/bricke)
+//!
+//! # Example
+//!
+//! ```
+//! use bricke::bricke;
+//!
+//! struct Foo {
+//!     a: i32,
+//!     b: String,
+//! }
+//!
+//! #[bricke(converter = "From", source = "Foo")]
+//! struct Bar {
+//!     a: i32,
+//!     #[bricke_field(rename = "b")]
+//!     c: String,
+//! }
+//! ```
+pub mod error;
+pub mod helpers;
+pub mod mapping;
+pub mod numeric;
+#[cfg(feature = "jiff")]
+pub mod time;
+pub mod traits;
+pub mod transforms;
 
-pub(crate) mod attributes;
-pub(crate) mod fields;
-pub(crate) mod item;
-
-use attributes::BrickeAttributes;
-use item::ProcessItem;
-
-/// bricke proc macro is a macro which generates a struct or enum with the specified attributes.
-/// This allows to convert a struct to another struct which may contains similar fields while also
-/// enabled to convert a field to another type or rename that field. Below are some examples below. Although more detailed examples can be found on the [README](https://github.com/shigedangao/bricke)
-///
-/// # Examples
-///
-/// ## Map a struct to another struct
-///
-/// ```
-/// use bricke::bricke;
-///
-/// struct Foo {
-///     a: i32,
-///     b: String,
-/// }
-///
-/// #[bricke(converter="From", source="Foo")]
-/// struct Bar {
-///     a: i32,
-///     #[bricke_field(rename="b")]
-///     c: String,
-/// }
-/// ```
-///
-/// ## Map a struct with a transform function
-///
-/// ```
-/// use bricke::bricke;
-///
-/// struct Bar {
-///     a: i32
-/// }
-///
-/// fn add_self(a: i32) -> i32 {
-///     a + a
-/// }
-///
-/// #[bricke(converter="From", source="Bar")]
-/// struct Target {
-///     #[bricke_field(transform_fn="add_self")]
-///     a: i32,
-/// }
-/// ```
-#[proc_macro_attribute]
-pub fn bricke(args: TokenStream, target: TokenStream) -> TokenStream {
-    let input_kind = parse_macro_input!(target as Item);
-
-    let mut attrs = BrickeAttributes::default();
-    let bricke_parser = syn::meta::parser(|meta| attrs.parse(meta));
-    parse_macro_input!(args with bricke_parser);
-
-    let expanded = match input_kind {
-        Item::Struct(mut item) => item.process(attrs, item::SupportedType::Struct),
-        Item::Enum(mut item) => item.process(attrs, item::SupportedType::Enum),
-        _ => unimplemented!("Type is not supported"),
-    };
-
-    quote! {
-        #expanded
-    }
-    .into()
-}
+pub use bricke_macros::{Bricke, bricke, bricke_map};
+pub use error::{BrickeError, BrickeErrorKind};
+pub use helpers::required;
+pub use mapping::{BrickeMapping, FieldMapping};
+pub use numeric::{SaturatingFrom, WrappingFrom, checked};
+pub use traits::{BrickeFromWith, BrickeMerge, BrickeUpdate};
+pub use transforms::{
+    dedup, duration_to_millis, empty_to_none, lowercase, millis_to_duration, none_if_empty,
+    secs_to_system_time, sort, system_time_to_secs, trim, uppercase,
+};
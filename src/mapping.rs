@@ -0,0 +1,51 @@
+/// FieldMapping describes how a target field is built from the source
+///
+/// - target refers to the name of the target field
+/// - sources refers to the names of the source fields consumed by the target field, excluded fields don't consume any
+/// - transform refers to the path of the transform function if there is any e.g: `convert::to_datetime`
+/// - fallible refers to whether the transform function returns a Result
+/// - excluded refers to whether the target field is set with its default value instead of the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMapping {
+    pub target: &'static str,
+    pub sources: &'static [&'static str],
+    pub transform: Option<&'static str>,
+    pub fallible: bool,
+    pub excluded: bool,
+}
+
+/// BrickeMapping exposes the mapping of a target built from the `Source` so that tooling can introspect it
+/// e.g: audit logs or API docs. It's implemented by the conversions generated with `describe = true`.
+///
+/// # Example
+///
+/// ```
+/// use bricke::{BrickeMapping, bricke};
+///
+/// struct UserRow {
+///     id: i64,
+///     mail: String,
+/// }
+///
+/// #[bricke(converter = "From", source = "UserRow", describe = true)]
+/// struct User {
+///     id: i64,
+///     #[bricke_field(rename = "mail")]
+///     email: String,
+/// }
+///
+/// let email = <User as BrickeMapping<UserRow>>::FIELDS[1];
+/// assert_eq!(email.target, "email");
+/// assert_eq!(email.sources, ["mail"]);
+/// ```
+pub trait BrickeMapping<Source> {
+    /// The name of the source type
+    const SOURCE: &'static str;
+    /// The mapping of each target field
+    const FIELDS: &'static [FieldMapping];
+
+    /// Get the mapping of a target field
+    fn field(target: &str) -> Option<&'static FieldMapping> {
+        Self::FIELDS.iter().find(|field| field.target == target)
+    }
+}
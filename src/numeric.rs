@@ -0,0 +1,81 @@
+//! Numeric conversions between integer types used by the fields set with `numeric = "..."`:
+//!
+//! - `checked` returns a [`BrickeError`] when the source value doesn't fit in the target type
+//! - `saturating` clamps the source value to the bounds of the target type
+//! - `wrapping` truncates the source value like `as`
+use crate::BrickeError;
+
+/// Convert an integer into another integer type, a value which doesn't fit in the target type returns an
+/// out of range error with the field path. It's used by the fields set with `numeric = "checked"`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(bricke::checked::<i64, u32>(42, "id"), Ok(42));
+/// assert_eq!(bricke::checked::<i64, u32>(-1, "id"), Err(bricke::BrickeError::out_of_range("id")));
+/// ```
+pub fn checked<S, T: TryFrom<S>>(value: S, field: &'static str) -> Result<T, BrickeError> {
+    T::try_from(value).map_err(|_| BrickeError::out_of_range(field))
+}
+
+/// SaturatingFrom converts an integer into another integer type, clamping the value to the bounds of the target type
+///
+/// # Example
+///
+/// ```
+/// use bricke::SaturatingFrom;
+///
+/// assert_eq!(u8::saturating_from(300_i32), u8::MAX);
+/// assert_eq!(u8::saturating_from(-1_i32), u8::MIN);
+/// ```
+pub trait SaturatingFrom<Source> {
+    fn saturating_from(value: Source) -> Self;
+}
+
+/// WrappingFrom converts an integer into another integer type, truncating the value like `as`
+///
+/// # Example
+///
+/// ```
+/// use bricke::WrappingFrom;
+///
+/// assert_eq!(u8::wrapping_from(300_i32), 44);
+/// assert_eq!(u8::wrapping_from(-1_i32), u8::MAX);
+/// ```
+pub trait WrappingFrom<Source> {
+    fn wrapping_from(value: Source) -> Self;
+}
+
+/// Implement the numeric conversions from each source integer type into every integer type. A signed value which
+/// doesn't fit is clamped to the lower bound when negative, an unsigned one is always clamped to the upper bound.
+macro_rules! impl_numeric {
+    ($sign:ident: $($source:ty),*) => {
+        $(
+            impl_numeric!(@targets $sign $source: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        )*
+    };
+    (@targets $sign:ident $source:ty: $($target:ty),*) => {
+        $(
+            impl SaturatingFrom<$source> for $target {
+                fn saturating_from(value: $source) -> Self {
+                    <$target>::try_from(value).unwrap_or_else(|_| impl_numeric!(@bound $sign value, $target))
+                }
+            }
+
+            impl WrappingFrom<$source> for $target {
+                fn wrapping_from(value: $source) -> Self {
+                    value as $target
+                }
+            }
+        )*
+    };
+    (@bound signed $value:ident, $target:ty) => {
+        if $value < 0 { <$target>::MIN } else { <$target>::MAX }
+    };
+    (@bound unsigned $value:ident, $target:ty) => {
+        <$target>::MAX
+    };
+}
+
+impl_numeric!(signed: i8, i16, i32, i64, i128, isize);
+impl_numeric!(unsigned: u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,152 @@
+//! Conversions between the [`jiff`] timestamps and their representations used by the fields set with
+//! `time = "..."`. This module is available with the `jiff` feature.
+//!
+//! - `epoch_seconds` converts a `Timestamp` or a `Zoned` from and to the seconds since the Unix epoch as `i64`
+//! - `epoch_millis` converts a `Timestamp` or a `Zoned` from and to the milliseconds since the Unix epoch as `i64`
+//! - `rfc3339` converts a `Timestamp` or a `Zoned` from and to an RFC 3339 `String` e.g: `2024-06-19T15:22:45Z`
+//!
+//! A timestamp is always converted into its representation, the other way around can fail e.g: an epoch value out of
+//! the supported range or a malformed string. Those conversions are only available with a fallible conversion. A
+//! `Zoned` built from an epoch value is in UTC, while a `Zoned` parsed from a string keeps the offset of the string.
+use crate::BrickeError;
+use jiff::{
+    Timestamp, Zoned,
+    fmt::temporal::Pieces,
+    tz::{Offset, TimeZone},
+};
+
+/// EpochSeconds is the format of the fields set with `time = "epoch_seconds"`
+pub struct EpochSeconds;
+
+/// EpochMillis is the format of the fields set with `time = "epoch_millis"`
+pub struct EpochMillis;
+
+/// Rfc3339 is the format of the fields set with `time = "rfc3339"`
+pub struct Rfc3339;
+
+/// TimeFrom converts a value into another according to the format, the conversion can't fail
+///
+/// # Example
+///
+/// ```
+/// use bricke::time::{EpochSeconds, TimeFrom};
+///
+/// let ts = jiff::Timestamp::from_second(60).unwrap();
+/// assert_eq!(<i64 as TimeFrom<_, EpochSeconds>>::time_from(ts), 60);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be converted infallibly from `{Source}`",
+    note = "the conversion can fail, use a fallible conversion e.g: `converter = \"TryFrom\"`"
+)]
+pub trait TimeFrom<Source, Format>: Sized {
+    fn time_from(value: Source) -> Self;
+}
+
+/// TryTimeFrom converts a value into another according to the format, an invalid value returns an error with the
+/// field path. Every [`TimeFrom`] conversion is also a [`TryTimeFrom`] conversion.
+///
+/// # Example
+///
+/// ```
+/// use bricke::time::{Rfc3339, TryTimeFrom};
+///
+/// let ts = <jiff::Timestamp as TryTimeFrom<_, Rfc3339>>::try_time_from("1970-01-01T00:01:00Z".to_string(), "at");
+/// assert_eq!(ts.unwrap().as_second(), 60);
+///
+/// let err = <jiff::Timestamp as TryTimeFrom<_, Rfc3339>>::try_time_from("yesterday".to_string(), "at");
+/// assert_eq!(err.unwrap_err().path(), "at");
+/// ```
+pub trait TryTimeFrom<Source, Format>: Sized {
+    fn try_time_from(value: Source, field: &'static str) -> Result<Self, BrickeError>;
+}
+
+impl<Source, Format, T: TimeFrom<Source, Format>> TryTimeFrom<Source, Format> for T {
+    fn try_time_from(value: Source, _field: &'static str) -> Result<Self, BrickeError> {
+        Ok(T::time_from(value))
+    }
+}
+
+impl TimeFrom<Timestamp, EpochSeconds> for i64 {
+    fn time_from(value: Timestamp) -> Self {
+        value.as_second()
+    }
+}
+
+impl TimeFrom<Zoned, EpochSeconds> for i64 {
+    fn time_from(value: Zoned) -> Self {
+        value.timestamp().as_second()
+    }
+}
+
+impl TimeFrom<Timestamp, EpochMillis> for i64 {
+    fn time_from(value: Timestamp) -> Self {
+        value.as_millisecond()
+    }
+}
+
+impl TimeFrom<Zoned, EpochMillis> for i64 {
+    fn time_from(value: Zoned) -> Self {
+        value.timestamp().as_millisecond()
+    }
+}
+
+impl TimeFrom<Timestamp, Rfc3339> for String {
+    fn time_from(value: Timestamp) -> Self {
+        value.to_string()
+    }
+}
+
+impl TimeFrom<Zoned, Rfc3339> for String {
+    fn time_from(value: Zoned) -> Self {
+        value
+            .timestamp()
+            .display_with_offset(value.offset())
+            .to_string()
+    }
+}
+
+impl TryTimeFrom<i64, EpochSeconds> for Timestamp {
+    fn try_time_from(value: i64, field: &'static str) -> Result<Self, BrickeError> {
+        Timestamp::from_second(value).map_err(|_| BrickeError::out_of_range(field))
+    }
+}
+
+impl TryTimeFrom<i64, EpochSeconds> for Zoned {
+    fn try_time_from(value: i64, field: &'static str) -> Result<Self, BrickeError> {
+        <Timestamp as TryTimeFrom<_, EpochSeconds>>::try_time_from(value, field)
+            .map(|ts| ts.to_zoned(TimeZone::UTC))
+    }
+}
+
+impl TryTimeFrom<i64, EpochMillis> for Timestamp {
+    fn try_time_from(value: i64, field: &'static str) -> Result<Self, BrickeError> {
+        Timestamp::from_millisecond(value).map_err(|_| BrickeError::out_of_range(field))
+    }
+}
+
+impl TryTimeFrom<i64, EpochMillis> for Zoned {
+    fn try_time_from(value: i64, field: &'static str) -> Result<Self, BrickeError> {
+        <Timestamp as TryTimeFrom<_, EpochMillis>>::try_time_from(value, field)
+            .map(|ts| ts.to_zoned(TimeZone::UTC))
+    }
+}
+
+impl TryTimeFrom<String, Rfc3339> for Timestamp {
+    fn try_time_from(value: String, field: &'static str) -> Result<Self, BrickeError> {
+        value
+            .parse()
+            .map_err(|err: jiff::Error| BrickeError::invalid(field, err.to_string()))
+    }
+}
+
+impl TryTimeFrom<String, Rfc3339> for Zoned {
+    fn try_time_from(value: String, field: &'static str) -> Result<Self, BrickeError> {
+        let ts = <Timestamp as TryTimeFrom<_, Rfc3339>>::try_time_from(value.clone(), field)?;
+        let offset = Pieces::parse(&value)
+            .ok()
+            .and_then(|pieces| pieces.to_numeric_offset())
+            .unwrap_or(Offset::UTC);
+
+        Ok(ts.to_zoned(TimeZone::fixed(offset)))
+    }
+}
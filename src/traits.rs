@@ -0,0 +1,28 @@
+/// BrickeMerge applies a partial source onto an existing target. It's implemented by the `Patch` converter,
+/// an infallible patch uses [`core::convert::Infallible`] as its error.
+pub trait BrickeMerge<Source> {
+    type Error;
+
+    /// Overwrite the fields of the target which are set in the source
+    fn merge(&mut self, source: Source) -> Result<(), Self::Error>;
+}
+
+/// BrickeUpdate assigns a source onto an existing target. It's implemented by the `Update` converter,
+/// an infallible update uses [`core::convert::Infallible`] as its error.
+pub trait BrickeUpdate<Source> {
+    type Error;
+
+    /// Overwrite the fields of the target with the fields of the source
+    fn update(&mut self, source: Source) -> Result<(), Self::Error>;
+}
+
+/// BrickeFromWith builds a target from a source along with a context. It's implemented by the `FromWith`
+/// converter, an infallible conversion uses [`core::convert::Infallible`] as its error.
+pub trait BrickeFromWith<Source, Context> {
+    type Error;
+
+    /// Build the target from the source, the context is passed to the transform functions
+    fn from_with(source: Source, ctx: &Context) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
+}
@@ -0,0 +1,72 @@
+//! Built-in transform functions which can be used with `#[bricke_field(transform = trim)]` or with their path
+//! e.g: `#[bricke_field(transform = "bricke::trim")]`
+
+use std::time::{Duration, SystemTime, SystemTimeError, UNIX_EPOCH};
+
+/// Remove the leading and trailing whitespaces
+pub fn trim(value: String) -> String {
+    match value.trim() {
+        trimmed if trimmed.len() == value.len() => value,
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Convert the string to lowercase
+pub fn lowercase(value: String) -> String {
+    value.to_lowercase()
+}
+
+/// Convert the string to uppercase
+pub fn uppercase(value: String) -> String {
+    value.to_uppercase()
+}
+
+/// Replace an empty string with None e.g: `Some("")` becomes `None`
+pub fn empty_to_none(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+/// Wrap a non empty string, an empty string becomes None
+pub fn none_if_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Convert the seconds elapsed since the unix epoch into a SystemTime
+pub fn secs_to_system_time(secs: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+/// Convert a SystemTime into the seconds elapsed since the unix epoch. A time before the unix epoch returns an
+/// error, use it along with `is_fallible = true`
+pub fn system_time_to_secs(time: SystemTime) -> Result<u64, SystemTimeError> {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+}
+
+/// Convert milliseconds into a Duration
+pub fn millis_to_duration(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+/// Convert a Duration into milliseconds, a Duration which doesn't fit in a u64 is saturated
+pub fn duration_to_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Remove the duplicated values while keeping the first occurrence of each value
+pub fn dedup<T: PartialEq>(values: Vec<T>) -> Vec<T> {
+    let mut unique = Vec::with_capacity(values.len());
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+
+    unique
+}
+
+/// Sort the values
+pub fn sort<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort();
+    values
+}